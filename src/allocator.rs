@@ -0,0 +1,167 @@
+#[cfg(feature = "allocator_api")]
+use core::alloc::{AllocError, Allocator};
+use core::alloc::{GlobalAlloc, Layout};
+#[cfg(feature = "allocator_api")]
+use core::ptr::NonNull;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::OnlyNull;
+
+/// # `NullAllocator`
+///
+/// An allocator that never allocates anything. Every allocation is an [`OnlyNull`], which makes it
+/// the fastest allocator ever written, and also very good at testing what happens when you run
+/// out of memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NullAllocator;
+
+impl NullAllocator {
+    /// Allocates memory for `layout`, or rather doesn't.
+    #[inline]
+    #[must_use]
+    pub const fn alloc_null(&self, layout: Layout) -> OnlyNull<u8> {
+        let _ = layout;
+        OnlyNull::null()
+    }
+}
+
+unsafe impl GlobalAlloc for NullAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.alloc_null(layout).into()
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {
+        // Nothing was ever allocated, so nothing can be deallocated.
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, _: *mut u8, layout: Layout, _: usize) -> *mut u8 {
+        self.alloc_null(layout).into()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+unsafe impl Allocator for NullAllocator {
+    #[inline]
+    fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Err(AllocError)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+        // See `GlobalAlloc::dealloc`.
+    }
+}
+
+/// # `FailAfter`
+///
+/// Wraps a real allocator, and lets `N` allocations through to it before it starts behaving like a
+/// [`NullAllocator`]. This way the out of memory path can be hit at exactly the allocation you want
+/// to test. Deallocations always go to the real allocator.
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Debug, Default)]
+pub struct FailAfter<const N: usize, A> {
+    inner: A,
+    used: AtomicUsize,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<const N: usize, A> FailAfter<N, A> {
+    /// Wraps `inner`, with the full budget of `N` allocations.
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: A) -> Self {
+        Self {
+            inner,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of allocations left before they start failing.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        N.saturating_sub(self.used.load(Ordering::Relaxed))
+    }
+
+    /// Refills the budget to `N` allocations.
+    #[inline]
+    pub fn reset(&self) {
+        self.used.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns the wrapped allocator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+
+    /// Takes one allocation out of the budget, returning false if there was nothing left.
+    fn take_one(&self) -> bool {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                if used < N {
+                    Some(used + 1)
+                } else {
+                    None
+                }
+            })
+            .is_ok()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+unsafe impl<const N: usize, A: GlobalAlloc> GlobalAlloc for FailAfter<N, A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if self.take_one() {
+            unsafe { self.inner.alloc(layout) }
+        } else {
+            NullAllocator.alloc_null(layout).into()
+        }
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if self.take_one() {
+            unsafe { self.inner.alloc_zeroed(layout) }
+        } else {
+            NullAllocator.alloc_null(layout).into()
+        }
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.inner.dealloc(ptr, layout) }
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if self.take_one() {
+            unsafe { self.inner.realloc(ptr, layout, new_size) }
+        } else {
+            NullAllocator.alloc_null(layout).into()
+        }
+    }
+}
+
+#[cfg(all(target_has_atomic = "ptr", feature = "allocator_api"))]
+unsafe impl<const N: usize, A: Allocator> Allocator for FailAfter<N, A> {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if self.take_one() {
+            self.inner.allocate(layout)
+        } else {
+            NullAllocator.allocate(layout)
+        }
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.inner.deallocate(ptr, layout) }
+    }
+}
@@ -0,0 +1,69 @@
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{NullOr, OnlyNull};
+
+/// # `AtomicPtrExt`
+///
+/// [`AtomicPtr`] methods for code that uses null to mean "empty slot", so that the empty state is
+/// an [`OnlyNull`] and the full state is a [`NonNull`], instead of both being `*mut T`.
+pub trait AtomicPtrExt<T> {
+    /// Empties the slot.
+    fn store_null(&self, null: OnlyNull<T>, order: Ordering);
+
+    /// Empties the slot, returning what was in it, if anything.
+    fn swap_to_null(&self, order: Ordering) -> Option<NonNull<T>>;
+
+    /// Fills the slot with `new`, if it's empty.
+    ///
+    /// # Errors
+    /// Fails if the slot wasn't empty, returning what was in it.
+    fn compare_exchange_null(
+        &self,
+        new: NonNull<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<OnlyNull<T>, NonNull<T>>;
+
+    /// Loads the pointer, and finds out whether it's null.
+    fn load_classified(&self, order: Ordering) -> NullOr<T>;
+
+    /// Returns true if the slot is empty, with relaxed ordering.
+    fn is_null_relaxed(&self) -> bool;
+}
+
+impl<T> AtomicPtrExt<T> for AtomicPtr<T> {
+    #[inline]
+    fn store_null(&self, null: OnlyNull<T>, order: Ordering) {
+        self.store(null.into(), order);
+    }
+
+    #[inline]
+    fn swap_to_null(&self, order: Ordering) -> Option<NonNull<T>> {
+        NonNull::new(self.swap(core::ptr::null_mut(), order))
+    }
+
+    #[inline]
+    fn compare_exchange_null(
+        &self,
+        new: NonNull<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<OnlyNull<T>, NonNull<T>> {
+        match self.compare_exchange(core::ptr::null_mut(), new.as_ptr(), success, failure) {
+            Ok(_) => Ok(OnlyNull::null()),
+            // SAFETY: The exchange only fails if the pointer wasn't null.
+            Err(current) => Err(unsafe { NonNull::new_unchecked(current) }),
+        }
+    }
+
+    #[inline]
+    fn load_classified(&self, order: Ordering) -> NullOr<T> {
+        NullOr::from(self.load(order))
+    }
+
+    #[inline]
+    fn is_null_relaxed(&self) -> bool {
+        self.load(Ordering::Relaxed).is_null()
+    }
+}
@@ -0,0 +1,126 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::{formatting, ConvertToOnlyNullError, OnlyNull};
+
+/// # `OnlyNullFfi`
+///
+/// An [`OnlyNull`] that you can actually hand to C. For sized pointees [`OnlyNull`] takes up no
+/// space at all, which is great for us but very confusing for C, which expects a whole pointer.
+/// This has the same layout as `*const T`, and is always zero.
+///
+/// C is not to be trusted, so whenever one of these comes back from the other side it has to be
+/// checked with [`OnlyNullFfi::get`] before it's an [`OnlyNull`] again.
+#[repr(transparent)]
+pub struct OnlyNullFfi<T> {
+    ptr: *const T,
+}
+
+// SAFETY: The pointer is never dereferenced by this type, whatever C put in it.
+unsafe impl<T> Send for OnlyNullFfi<T> {}
+
+// SAFETY: See above.
+unsafe impl<T> Sync for OnlyNullFfi<T> {}
+
+impl<T> OnlyNullFfi<T> {
+    /// Creates a null pointer.
+    #[inline]
+    #[must_use]
+    pub const fn null() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+
+    nightly_const! {
+        /// Checks that the pointer is still null, in case it was handed to us by somebody who
+        /// didn't know any better.
+        ///
+        /// # Errors
+        /// Fails if the pointer isn't null, returning it in the error.
+        #[inline]
+        pub fn get(self) -> Result<OnlyNull<T>, ConvertToOnlyNullError<*const T>> {
+            OnlyNull::from_ptr(self.ptr)
+        }
+    }
+
+    /// Returns the raw pointer, without checking it.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(self) -> *const T {
+        self.ptr
+    }
+}
+
+impl<T> fmt::Debug for OnlyNullFfi<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatting::debug_pointer::<T>(f, "OnlyNullFfi", self.ptr.addr(), ())
+    }
+}
+
+impl<T> fmt::Pointer for OnlyNullFfi<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr, f)
+    }
+}
+
+impl<T> Clone for OnlyNullFfi<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for OnlyNullFfi<T> {}
+
+// These compare the address, so that a pointer C broke isn't equal to a null one.
+impl<T> PartialEq for OnlyNullFfi<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl<T> Eq for OnlyNullFfi<T> {}
+
+impl<T> Hash for OnlyNullFfi<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
+impl<T> Default for OnlyNullFfi<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> From<OnlyNull<T>> for OnlyNullFfi<T> {
+    #[inline]
+    fn from(_: OnlyNull<T>) -> Self {
+        Self::null()
+    }
+}
+
+impl<T> TryFrom<OnlyNullFfi<T>> for OnlyNull<T> {
+    type Error = ConvertToOnlyNullError<*const T>;
+
+    fn try_from(ptr: OnlyNullFfi<T>) -> Result<Self, Self::Error> {
+        ptr.get()
+    }
+}
+
+/// Writes a C declaration for an [`OnlyNullFfi`] pointing to `c_pointee`, named `name`.
+///
+/// This is meant to be called from a build script, so that the header the C side sees can't drift
+/// away from what the Rust side actually passes.
+///
+/// # Errors
+/// Only fails if writing to `out` fails.
+pub fn write_c_declaration<W: fmt::Write>(out: &mut W, name: &str, c_pointee: &str) -> fmt::Result {
+    writeln!(
+        out,
+        "/* A pointer to `{c_pointee}` that must always be NULL. */"
+    )?;
+    writeln!(out, "typedef const {c_pointee} *{name};")
+}
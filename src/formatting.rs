@@ -0,0 +1,69 @@
+use core::fmt;
+
+use crate::pointee::{DynMetadata, Pointee};
+
+/// Formats a pointer the way the pointer types in this crate print themselves with `{:?}`, like
+/// `OnlyNull<[u8]>(0x0, len=12)`.
+pub(crate) fn debug_pointer<T>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    addr: usize,
+    meta: T::Metadata,
+) -> fmt::Result
+where
+    T: ?Sized + Pointee,
+{
+    write!(f, "{}<{}>({:#x}", name, core::any::type_name::<T>(), addr)?;
+    meta.fmt_metadata(f)?;
+    f.write_str(")")
+}
+
+/// How metadata shows up in [`debug_pointer`]. Every kind of metadata there is gets its own
+/// format.
+///
+/// This is a bound on the metadata of [`Pointee`] on both backends, so there is nothing else to
+/// fall back on.
+pub trait FmtMetadata {
+    /// Writes the metadata, with a leading comma if there is anything to write.
+    ///
+    /// # Errors
+    /// Only fails if writing fails.
+    fn fmt_metadata(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl FmtMetadata for () {
+    fn fmt_metadata(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl FmtMetadata for usize {
+    fn fmt_metadata(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", len={self}")
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<U> FmtMetadata for DynMetadata<U>
+where
+    U: ?Sized + core::ptr::Pointee<Metadata = Self>,
+{
+    fn fmt_metadata(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ", vtable={:#x}, size={}, align={}",
+            crate::pointee::vtable_addr(*self),
+            self.size_of(),
+            self.align_of()
+        )
+    }
+}
+
+/// The size and alignment live in the vtable, but stable Rust has no way to read them without an
+/// actual value, so only the vtable is printed.
+#[cfg(not(feature = "nightly"))]
+impl<U: ?Sized> FmtMetadata for DynMetadata<U> {
+    fn fmt_metadata(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", vtable={:#x}", self.vtable_addr())
+    }
+}
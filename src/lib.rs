@@ -1,10 +1,350 @@
+//! Pointers that can only be null, and their relatives.
+//!
+//! # Backends
+//! Pointer metadata comes from `core::ptr::Pointee` with the `nightly` feature, and from this
+//! crate's own [`Pointee`] without it. The API is the same on both, except for:
+//! - Trait objects, which have to be registered with [`dyn_pointee!`] on stable. The ones for
+//!   traits from `core` already are, so only other crates' traits are out of reach.
+//! - `null_dyn`, `unsize` and `into_dyn`, which need the `nightly` feature.
+//!   [`OnlyNull::null_dyn_with`] and [`OnlyNull::unsize_with`] do the same on either backend, with
+//!   a closure that does the coercion.
+//! - `size_of_val_hint` and `align_of_val_hint`, and the `size=` and `align=` in the `Debug` output
+//!   of trait object pointers. Stable Rust can't read a vtable without a value, so these need the
+//!   `nightly` feature.
+//! - Building and taking apart pointers in constant expressions, which needs the `nightly` feature.
+
 #![no_std]
 #![deny(clippy::all, clippy::pedantic, rust_2018_idioms)]
-#![feature(ptr_metadata)]
+#![cfg_attr(feature = "nightly", feature(ptr_metadata, unsize))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl, const_convert))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+use core::cmp::Ordering;
 use core::convert::TryFrom;
+use core::fmt;
+use core::hash::Hash;
 use core::marker::PhantomData;
-use core::ptr::Pointee;
+#[cfg(feature = "nightly")]
+use core::marker::Unsize;
+use core::ptr::NonNull;
+
+/// Defines functions that are `const` with the `nightly` feature, where `core::ptr` can build and
+/// take apart pointers in constant expressions, and plain functions without it.
+macro_rules! nightly_const {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($args:tt)*) -> $ret:ty $body:block
+    )*) => {$(
+        #[cfg(feature = "nightly")]
+        $(#[$attr])*
+        $vis const fn $name($($args)*) -> $ret $body
+
+        #[cfg(not(feature = "nightly"))]
+        $(#[$attr])*
+        $vis fn $name($($args)*) -> $ret $body
+    )*};
+}
+
+/// Implements `fmt::Pointer`, `Clone`, `Copy`, the comparisons, `Hash` and `Default` for one of the
+/// pointer types, so that they all agree. They are implemented by hand, since deriving them would
+/// put bounds on the pointee, even though at most the metadata is stored.
+///
+/// Pointers compare and hash by `key`, which is whatever the pointer stores, or `()` if it stores
+/// nothing. `pointer` names the method that gives the raw pointer for `{:p}`.
+macro_rules! pointer_traits {
+    (
+        impl[$($gen:tt)*] $ty:ty where [$($bound:tt)*],
+        key: |$ptr:ident| $key:expr,
+        pointer: $as_ptr:ident,
+        default where [$($default_bound:tt)*]: $default:expr $(,)?
+    ) => {
+        impl<$($gen)*> core::fmt::Pointer for $ty
+        where
+            $($bound)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Pointer::fmt(&self.$as_ptr(), f)
+            }
+        }
+
+        // Clippy would rather this was derived when the bounds already imply `Clone`, like
+        // `FnPtr` does.
+        #[allow(clippy::expl_impl_clone_on_copy)]
+        impl<$($gen)*> Clone for $ty
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<$($gen)*> Copy for $ty
+        where
+            $($bound)*
+        {
+        }
+
+        impl<$($gen)*> PartialEq for $ty
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                let key = |$ptr: &Self| $key;
+                PartialEq::eq(&key(self), &key(other))
+            }
+        }
+
+        impl<$($gen)*> Eq for $ty
+        where
+            $($bound)*
+        {
+        }
+
+        impl<$($gen)*> PartialOrd for $ty
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$($gen)*> Ord for $ty
+        where
+            $($bound)*
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                let key = |$ptr: &Self| $key;
+                Ord::cmp(&key(self), &key(other))
+            }
+        }
+
+        impl<$($gen)*> core::hash::Hash for $ty
+        where
+            $($bound)*
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                let key = |$ptr: &Self| $key;
+                core::hash::Hash::hash(&key(self), state);
+            }
+        }
+
+        impl<$($gen)*> Default for $ty
+        where
+            $($default_bound)*
+        {
+            #[inline]
+            fn default() -> Self {
+                $default
+            }
+        }
+    };
+}
+
+/// Implements what every pointer that only stores metadata has in common: building it from the
+/// metadata and casting it, the traits below and the conversions to and from raw pointers. The type
+/// needs an `addr` method, and `as_ptr`/`from_ptr` methods and their `mut` counterparts, which are
+/// where the address is decided and checked. Passing `const` makes the conversions `const` with
+/// the `const_trait_impl` feature, which needs those methods to be `const` too.
+///
+/// The metadata is always `Copy`, `Ord`, `Hash` and `Debug`, so nothing else needs a bound.
+macro_rules! metadata_pointer {
+    ($name:ident<T $(, const $param:ident: usize)?>, $error:ident $(, $const:ident)?) => {
+        // SAFETY: The pointer is never dereferenced by this type, and the metadata is always `Send`
+        // and `Sync`.
+        unsafe impl<T $(, const $param: usize)?> Send for $name<T $(, $param)?>
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+        }
+
+        // SAFETY: See above.
+        unsafe impl<T $(, const $param: usize)?> Sync for $name<T $(, $param)?>
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+        }
+
+        impl<T $(, const $param: usize)?> $name<T $(, $param)?>
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+            /// Creates the pointer, carrying the given metadata.
+            ///
+            /// This is the only way to get a pointer to an unsized type that doesn't have a more
+            /// specific constructor.
+            #[inline]
+            #[must_use]
+            pub const fn from_metadata(meta: T::Metadata) -> Self {
+                Self {
+                    meta,
+                    _phantom: core::marker::PhantomData,
+                }
+            }
+
+            /// Returns the metadata of the pointer.
+            #[inline]
+            #[must_use]
+            pub const fn metadata(self) -> T::Metadata {
+                self.meta
+            }
+
+            /// Returns a pointer of the same type, but with different metadata.
+            #[inline]
+            #[must_use]
+            pub const fn with_metadata(self, meta: T::Metadata) -> Self {
+                Self::from_metadata(meta)
+            }
+
+            /// Creates a pointer of another type by transforming the metadata.
+            #[inline]
+            #[must_use]
+            pub fn map_metadata<U, F>(self, f: F) -> $name<U $(, $param)?>
+            where
+                U: ?Sized + $crate::Pointee,
+                F: FnOnce(T::Metadata) -> U::Metadata,
+            {
+                $name::from_metadata(f(self.meta))
+            }
+
+            /// Casts to a pointer of another type, at the same address.
+            #[inline]
+            #[must_use]
+            pub const fn cast<U>(self) -> $name<U $(, $param)?>
+            where
+                U: ?Sized + $crate::Pointee<Metadata = T::Metadata>,
+            {
+                $name::from_metadata(self.meta)
+            }
+        }
+
+        impl<T $(, const $param: usize)?> core::fmt::Debug for $name<T $(, $param)?>
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::formatting::debug_pointer::<T>(f, stringify!($name), self.addr(), self.meta)
+            }
+        }
+
+        pointer_traits! {
+            impl[T $(, const $param: usize)?] $name<T $(, $param)?>
+                where [T: ?Sized + $crate::Pointee],
+            key: |ptr| ptr.meta,
+            pointer: as_ptr,
+            default where [T: ?Sized + $crate::Pointee, T::Metadata: Default]:
+                Self::from_metadata(T::Metadata::default()),
+        }
+
+        #[cfg(feature = "const_trait_impl")]
+        metadata_pointer!(@conversions [$($const)?] $name<T $(, const $param: usize)?>, $error);
+        #[cfg(not(feature = "const_trait_impl"))]
+        metadata_pointer!(@conversions [] $name<T $(, const $param: usize)?>, $error);
+    };
+    (
+        @conversions [$($const:ident)?]
+        $name:ident<T $(, const $param:ident: usize)?>, $error:ident
+    ) => {
+        impl<T $(, const $param: usize)?> $($const)? From<$name<T $(, $param)?>> for *const T
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+            #[inline]
+            fn from(ptr: $name<T $(, $param)?>) -> Self {
+                ptr.as_ptr()
+            }
+        }
+
+        impl<T $(, const $param: usize)?> $($const)? From<$name<T $(, $param)?>> for *mut T
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+            #[inline]
+            fn from(ptr: $name<T $(, $param)?>) -> Self {
+                ptr.as_mut_ptr()
+            }
+        }
+
+        impl<T $(, const $param: usize)?> $($const)? core::convert::TryFrom<*const T>
+            for $name<T $(, $param)?>
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+            type Error = $crate::$error<*const T>;
+
+            fn try_from(ptr: *const T) -> Result<Self, Self::Error> {
+                Self::from_ptr(ptr)
+            }
+        }
+
+        impl<T $(, const $param: usize)?> $($const)? core::convert::TryFrom<*mut T>
+            for $name<T $(, $param)?>
+        where
+            T: ?Sized + $crate::Pointee,
+        {
+            type Error = $crate::$error<*mut T>;
+
+            fn try_from(ptr: *mut T) -> Result<Self, Self::Error> {
+                Self::from_mut_ptr(ptr)
+            }
+        }
+    };
+}
+
+mod allocator;
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
+mod ffi;
+mod formatting;
+mod null_or;
+mod null_terminated;
+mod only_addr;
+mod only_dangling;
+mod only_null_fn;
+mod only_null_mut;
+mod option;
+mod pointee;
+mod pointer_like;
+mod ptr;
+mod ptr_ext;
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(target_has_atomic = "ptr")]
+pub use allocator::FailAfter;
+pub use allocator::NullAllocator;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicPtrExt;
+pub use ffi::{write_c_declaration, OnlyNullFfi};
+pub use null_or::NullOr;
+pub use null_terminated::NullTerminated;
+#[cfg(feature = "alloc")]
+pub use null_terminated::NullTerminatedBuilder;
+pub use only_addr::OnlyAddr;
+pub use only_dangling::OnlyDangling;
+pub use only_null_fn::{FnPtr, OnlyNullFn};
+pub use only_null_mut::OnlyNullMut;
+pub use pointee::{DynMetadata, Pointee};
+pub use pointer_like::{KnownNullability, PointerLike};
+pub use ptr::{NonNullMarker, Null, Nullability, Ptr, PtrState, Unknown};
+pub use ptr_ext::PtrExt;
+#[cfg(feature = "serde")]
+pub use serde_impls::SerdeMetadata;
+
+/// What [`dyn_pointee!`] expands to on stable. None of this is part of the API.
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::pointee::polyfill::DynPointee;
+}
 
 /// # `OnlyNull`
 ///
@@ -15,23 +355,45 @@ use core::ptr::Pointee;
 ///
 /// If you need to convert into a traditional form of pointer, that is trivial since [`OnlyNull`]
 /// implements [`Into`] to these types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct OnlyNull<T: ?Sized>
+///
+/// Unlike `*const T` it is invariant in `T`, the same as [`OnlyNullMut`]. It stores `T::Metadata`,
+/// and the compiler can't see through that to tell how `T` is used. [`OnlyNull::cast`] changes
+/// the type instead, since the metadata stays the same.
+/// ```compile_fail
+/// use packed::OnlyNull;
+///
+/// fn shorten<'a>(ptr: OnlyNull<&'static u8>) -> OnlyNull<&'a u8> {
+///     ptr
+/// }
+/// ```
+/// ```
+/// use packed::OnlyNull;
+///
+/// fn shorten<'a>(ptr: OnlyNull<&'static u8>) -> OnlyNull<&'a u8> {
+///     ptr.cast()
+/// }
+/// ```
+pub struct OnlyNull<T>
 where
-    T: Pointee,
+    T: ?Sized + Pointee,
 {
     meta: T::Metadata,
     _phantom: PhantomData<*const T>,
 }
 
-impl<T: ?Sized> OnlyNull<T>
+metadata_pointer!(OnlyNull<T>, ConvertToOnlyNullError, const);
+
+impl<T> OnlyNull<T>
 where
-    T: Pointee<Metadata = ()>,
+    T: ?Sized + Pointee<Metadata = ()>,
 {
+    /// The null pointer.
+    pub const NULL: Self = Self::null();
+
     /// Creates a null pointer
     #[inline]
     #[must_use]
-    pub fn null() -> Self {
+    pub const fn null() -> Self {
         Self {
             meta: (),
             _phantom: PhantomData,
@@ -39,69 +401,742 @@ where
     }
 }
 
-impl<T: ?Sized> OnlyNull<T>
+impl<T> OnlyNull<T>
 where
-    T: Pointee,
+    T: ?Sized + Pointee,
 {
-    /// Casts to a pointer of another type.
+    nightly_const! {
+        /// Converts to a raw null pointer with the same metadata.
+        #[inline]
+        #[must_use]
+        pub fn as_ptr(self) -> *const T {
+            pointee::from_raw_parts(core::ptr::null::<()>(), self.meta)
+        }
+
+        /// Converts to a mutable raw null pointer with the same metadata.
+        #[inline]
+        #[must_use]
+        pub fn as_mut_ptr(self) -> *mut T {
+            pointee::from_raw_parts_mut(core::ptr::null_mut::<()>(), self.meta)
+        }
+
+        /// Converts from a raw pointer, if it's null.
+        ///
+        /// This is the same as the [`TryFrom`] implementation, but with the `nightly` feature it's
+        /// usable in constant expressions.
+        ///
+        /// # Errors
+        /// Fails if the pointer isn't null, returning it in the error.
+        #[inline]
+        pub fn from_ptr(ptr: *const T) -> Result<Self, ConvertToOnlyNullError<*const T>> {
+            if ptr.is_null() {
+                Ok(Self::from_metadata(pointee::metadata(ptr)))
+            } else {
+                Err(ConvertToOnlyNullError { ptr })
+            }
+        }
+
+        /// Converts from a mutable raw pointer, if it's null.
+        ///
+        /// This is the same as the [`TryFrom`] implementation, but with the `nightly` feature it's
+        /// usable in constant expressions.
+        ///
+        /// # Errors
+        /// Fails if the pointer isn't null, returning it in the error.
+        #[inline]
+        pub fn from_mut_ptr(ptr: *mut T) -> Result<Self, ConvertToOnlyNullError<*mut T>> {
+            if ptr.is_null() {
+                Ok(Self::from_metadata(pointee::metadata(ptr)))
+            } else {
+                Err(ConvertToOnlyNullError { ptr })
+            }
+        }
+    }
+
+    /// Unsizes the pointer, the same way a raw pointer would be coerced from `*const T` to
+    /// `*const U`.
+    ///
+    /// [`OnlyNull`] can't implement `CoerceUnsized`, since that would require the metadata itself
+    /// to be coercible, so this has to be done explicitly.
+    #[cfg(feature = "nightly")]
+    #[inline]
     #[must_use]
-    pub fn cast<U: ?Sized>(self) -> OnlyNull<U>
+    pub const fn unsize<U>(self) -> OnlyNull<U>
     where
-        U: Pointee<Metadata = T::Metadata>,
+        T: Unsize<U>,
+        U: ?Sized + Pointee,
     {
-        OnlyNull {
-            meta: self.meta,
-            _phantom: PhantomData,
+        let ptr: *const U = self.as_ptr();
+        OnlyNull::from_metadata(pointee::metadata(ptr))
+    }
+
+    /// Converts to a trait object pointer, with the vtable of `T`.
+    #[cfg(feature = "nightly")]
+    #[inline]
+    #[must_use]
+    pub const fn into_dyn<U>(self) -> OnlyNull<U>
+    where
+        T: Unsize<U>,
+        U: ?Sized + Pointee<Metadata = DynMetadata<U>>,
+    {
+        self.unsize()
+    }
+
+    /// Unsizes the pointer through `coerce`, which is handed the raw null pointer and should only
+    /// coerce it, like `|ptr| ptr as *const dyn Debug`. Unlike `unsize`, this works on stable.
+    ///
+    /// Only the metadata of what `coerce` returns is kept, so the result is null whatever it does.
+    /// ```
+    /// use core::fmt::Debug;
+    /// use packed::OnlyNull;
+    ///
+    /// let slice = OnlyNull::<[u8; 4]>::null().unsize_with(|ptr| ptr as *const [u8]);
+    /// assert_eq!(slice.len(), 4);
+    ///
+    /// let debug = OnlyNull::<u8>::null().unsize_with(|ptr| ptr as *const dyn Debug);
+    /// assert!(debug.as_ptr().is_null());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn unsize_with<U, F>(self, coerce: F) -> OnlyNull<U>
+    where
+        U: ?Sized + Pointee,
+        F: FnOnce(*const T) -> *const U,
+    {
+        OnlyNull::from_metadata(pointee::metadata(coerce(self.as_ptr())))
+    }
+
+    /// Casts to a pointer to a sized type, discarding the metadata.
+    #[inline]
+    #[must_use]
+    pub const fn cast_to_sized<U>(self) -> OnlyNull<U> {
+        OnlyNull::null()
+    }
+}
+
+/// The same methods that raw pointers have, except the answer is always known in advance.
+impl<T> OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Returns true if the pointer is null. It is.
+    #[inline]
+    #[must_use]
+    pub const fn is_null(self) -> bool {
+        true
+    }
+
+    /// Returns the address of the pointer, which is zero.
+    #[inline]
+    #[must_use]
+    pub const fn addr(self) -> usize {
+        0
+    }
+
+    /// Returns true if the pointer is aligned, which zero always is.
+    #[inline]
+    #[must_use]
+    pub const fn is_aligned(self) -> bool {
+        true
+    }
+
+    /// Splits the pointer into its address and metadata, the same way raw pointers do.
+    #[inline]
+    #[must_use]
+    pub const fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        (core::ptr::null(), self.meta)
+    }
+
+    /// Returns a shared reference to the value, which there isn't one of, so it's always [`None`].
+    ///
+    /// Unlike the raw pointer version this doesn't have to be unsafe, since there's nothing to get
+    /// wrong.
+    #[inline]
+    #[must_use]
+    pub const fn as_ref<'a>(self) -> Option<&'a T> {
+        None
+    }
+
+    /// Returns a mutable reference to the value, which is always [`None`].
+    #[inline]
+    #[must_use]
+    pub const fn as_mut<'a>(self) -> Option<&'a mut T> {
+        None
+    }
+}
+
+impl<T> OnlyNull<T> {
+    /// Offsets the pointer by `count` elements, which has to be zero.
+    ///
+    /// # Safety
+    /// The same rules as `<*const T>::offset` apply. Since a null pointer isn't part of any
+    /// allocation, that means the offset in bytes has to be zero.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn offset(self, count: isize) -> Self {
+        let _ = count;
+        self
+    }
+
+    /// Adds `count` elements to the pointer, which has to be zero.
+    ///
+    /// # Safety
+    /// The same rules as `<*const T>::add` apply, see [`OnlyNull::offset`].
+    #[inline]
+    #[must_use]
+    pub const unsafe fn add(self, count: usize) -> Self {
+        let _ = count;
+        self
+    }
+
+    /// Subtracts `count` elements from the pointer, which has to be zero.
+    ///
+    /// # Safety
+    /// The same rules as `<*const T>::sub` apply, see [`OnlyNull::offset`].
+    #[inline]
+    #[must_use]
+    pub const unsafe fn sub(self, count: usize) -> Self {
+        let _ = count;
+        self
+    }
+
+    /// Offsets the pointer by `count` elements with wrapping arithmetic. The result is only still
+    /// null if the offset in bytes wraps around to zero.
+    ///
+    /// Unlike [`OnlyNull::offset`] this is safe to call with any count, so the result can't keep
+    /// the type and is checked instead.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_offset(self, count: isize) -> NullOr<T> {
+        NullOr::from(self.as_ptr().wrapping_offset(count))
+    }
+
+    /// Adds `count` elements to the pointer with wrapping arithmetic, see
+    /// [`OnlyNull::wrapping_offset`].
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add(self, count: usize) -> NullOr<T> {
+        NullOr::from(self.as_ptr().wrapping_add(count))
+    }
+
+    /// Subtracts `count` elements from the pointer with wrapping arithmetic, see
+    /// [`OnlyNull::wrapping_offset`].
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sub(self, count: usize) -> NullOr<T> {
+        NullOr::from(self.as_ptr().wrapping_sub(count))
+    }
+
+    /// Reads the value. There is no value.
+    ///
+    /// # Safety
+    /// This can never be called safely, the pointer is null.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn read(self) -> T {
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+
+    /// Reads the value without moving it, volatilely. There is still no value.
+    ///
+    /// # Safety
+    /// This can never be called safely, the pointer is null.
+    #[inline]
+    #[must_use]
+    pub unsafe fn read_volatile(self) -> T {
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+
+    /// Reads the value without requiring alignment. Alignment was never the problem.
+    ///
+    /// # Safety
+    /// This can never be called safely, the pointer is null.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn read_unaligned(self) -> T {
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+
+    /// Writes a value to where the pointer points, which is nowhere.
+    ///
+    /// # Safety
+    /// This can never be called safely, the pointer is null.
+    #[inline]
+    pub const unsafe fn write(self, value: T) {
+        core::mem::forget(value);
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+
+    /// Writes a value volatilely to where the pointer points, which is still nowhere.
+    ///
+    /// # Safety
+    /// This can never be called safely, the pointer is null.
+    #[inline]
+    pub unsafe fn write_volatile(self, value: T) {
+        core::mem::forget(value);
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+
+    /// Writes a value without requiring alignment.
+    ///
+    /// # Safety
+    /// This can never be called safely, the pointer is null.
+    #[inline]
+    pub const unsafe fn write_unaligned(self, value: T) {
+        core::mem::forget(value);
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+}
+
+impl<T> OnlyNull<T> {
+    /// Casts to a pointer of a possibly unsized type, with the given metadata.
+    #[inline]
+    #[must_use]
+    pub const fn cast_from_sized<U>(self, meta: U::Metadata) -> OnlyNull<U>
+    where
+        U: ?Sized + Pointee,
+    {
+        OnlyNull::from_metadata(meta)
+    }
+}
+
+impl<T> OnlyNull<[T]> {
+    /// Creates a null slice pointer with the given length.
+    #[inline]
+    #[must_use]
+    pub const fn null_slice(len: usize) -> Self {
+        Self::from_metadata(len)
+    }
+
+    /// Returns the length of the slice that isn't pointed to.
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.meta
+    }
+
+    /// Returns true if the length of the slice is zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.meta == 0
+    }
+
+    /// Casts to a pointer to the first element of the slice.
+    #[inline]
+    #[must_use]
+    pub const fn element(self) -> OnlyNull<T> {
+        self.cast_to_sized()
+    }
+
+    /// Casts to a slice of another element type, rescaling the length so that the slice covers
+    /// the same number of bytes.
+    ///
+    /// # Errors
+    /// Fails if the size in bytes of the slice isn't a multiple of the size of `U`, or if the
+    /// size in bytes overflows a `usize`. Casting to a zero sized `U` is only allowed if `T` is
+    /// also zero sized, in which case the length is kept as is.
+    pub const fn cast_slice<U>(self) -> Result<OnlyNull<[U]>, CastSliceError> {
+        let from_size = core::mem::size_of::<T>();
+        let to_size = core::mem::size_of::<U>();
+
+        if to_size == 0 {
+            return if from_size == 0 {
+                Ok(OnlyNull::null_slice(self.meta))
+            } else {
+                Err(CastSliceError)
+            };
+        }
+
+        match self.meta.checked_mul(from_size) {
+            Some(bytes) if bytes % to_size == 0 => Ok(OnlyNull::null_slice(bytes / to_size)),
+            _ => Err(CastSliceError),
         }
     }
 }
 
-impl<T> From<OnlyNull<T>> for *const T {
+impl OnlyNull<[u8]> {
+    /// Casts a byte slice pointer to a string pointer of the same length.
     #[inline]
     #[must_use]
-    fn from(_: OnlyNull<T>) -> Self {
-        core::ptr::null()
+    pub const fn to_str(self) -> OnlyNull<str> {
+        OnlyNull::null_str(self.meta)
     }
 }
 
-impl<T> From<OnlyNull<T>> for *mut T {
+impl<T, const N: usize> OnlyNull<[T; N]> {
+    /// Converts a null array pointer to a null slice pointer of length `N`.
     #[inline]
     #[must_use]
-    fn from(_: OnlyNull<T>) -> Self {
-        core::ptr::null_mut()
+    pub const fn unsize_array(self) -> OnlyNull<[T]> {
+        OnlyNull::null_slice(N)
+    }
+}
+
+impl OnlyNull<str> {
+    /// Creates a null string pointer with the given length in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn null_str(len: usize) -> Self {
+        Self::from_metadata(len)
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.meta
+    }
+
+    /// Returns true if the length of the string is zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.meta == 0
+    }
+
+    /// Casts a string pointer to a byte slice pointer of the same length.
+    #[inline]
+    #[must_use]
+    pub const fn to_bytes(self) -> OnlyNull<[u8]> {
+        OnlyNull::null_slice(self.meta)
+    }
+}
+
+impl<T> OnlyNull<T>
+where
+    T: ?Sized + Pointee<Metadata = DynMetadata<T>>,
+{
+    /// Creates a null trait object pointer, with the vtable of the concrete type `U`. `coerce` is
+    /// handed a null `*const U` to turn into a trait object pointer, see
+    /// [`OnlyNull::unsize_with`].
+    ///
+    /// This is how to do what `null_dyn` does on stable.
+    /// ```
+    /// use core::fmt::Debug;
+    /// use packed::OnlyNull;
+    ///
+    /// let null = OnlyNull::<dyn Debug>::null_dyn_with(|ptr: *const u32| ptr);
+    /// assert!(null.as_ptr().is_null());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn null_dyn_with<U, F>(coerce: F) -> Self
+    where
+        F: FnOnce(*const U) -> *const T,
+    {
+        OnlyNull::<U>::null().unsize_with(coerce)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T> OnlyNull<T>
+where
+    T: ?Sized + Pointee<Metadata = DynMetadata<T>>,
+{
+    /// Creates a null trait object pointer, with the vtable of the concrete type `U`.
+    #[inline]
+    #[must_use]
+    pub const fn null_dyn<U: Unsize<T>>() -> Self {
+        let ptr: *const T = core::ptr::null::<U>();
+        Self::from_metadata(ptr.to_raw_parts().1)
+    }
+
+    /// The size of the value that would be pointed to, according to the vtable.
+    #[inline]
+    #[must_use]
+    pub fn size_of_val_hint(self) -> usize {
+        self.meta.size_of()
+    }
+
+    /// The alignment of the value that would be pointed to, according to the vtable.
+    #[inline]
+    #[must_use]
+    pub fn align_of_val_hint(self) -> usize {
+        self.meta.align_of()
+    }
+}
+
+/// Prints only the address, which is always `0x0`, leaving out the metadata.
+impl<T> fmt::Display for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr().cast::<()>(), f)
+    }
+}
+
+/// Compares a null pointer with `meta` against `ptr`, the same way comparing two raw pointers does,
+/// by address first and metadata second.
+#[inline]
+fn cmp_raw<T>(meta: T::Metadata, ptr: *const T) -> Ordering
+where
+    T: ?Sized + Pointee,
+{
+    (0, meta).cmp(&(ptr.cast::<()>().addr(), pointee::metadata(ptr)))
+}
+
+macro_rules! raw_pointer_comparisons {
+    ($($ptr:tt)*) => {
+        impl<T> PartialEq<$($ptr)* T> for OnlyNull<T>
+        where
+            T: ?Sized + Pointee,
+        {
+            #[inline]
+            fn eq(&self, other: &$($ptr)* T) -> bool {
+                cmp_raw(self.meta, *other) == Ordering::Equal
+            }
+        }
+
+        impl<T> PartialEq<OnlyNull<T>> for $($ptr)* T
+        where
+            T: ?Sized + Pointee,
+        {
+            #[inline]
+            fn eq(&self, other: &OnlyNull<T>) -> bool {
+                other == self
+            }
+        }
+
+        impl<T> PartialOrd<$($ptr)* T> for OnlyNull<T>
+        where
+            T: ?Sized + Pointee,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &$($ptr)* T) -> Option<Ordering> {
+                Some(cmp_raw(self.meta, *other))
+            }
+        }
+
+        impl<T> PartialOrd<OnlyNull<T>> for $($ptr)* T
+        where
+            T: ?Sized + Pointee,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &OnlyNull<T>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+raw_pointer_comparisons!(*const);
+raw_pointer_comparisons!(*mut);
+
+// `None` is what every `OnlyNull` turns into, whatever its metadata, so they're all equal to it.
+impl<T> PartialEq<Option<NonNull<T>>> for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn eq(&self, other: &Option<NonNull<T>>) -> bool {
+        other.is_none()
     }
 }
 
-impl<T> TryFrom<*const T> for OnlyNull<T> {
-    type Error = ConvertToOnlyNullError;
+impl<T> PartialEq<OnlyNull<T>> for Option<NonNull<T>>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn eq(&self, other: &OnlyNull<T>) -> bool {
+        other == self
+    }
+}
 
-    fn try_from(ptr: *const T) -> Result<Self, Self::Error> {
-        if ptr.is_null() {
-            Ok(Self {
-                meta: ptr.to_raw_parts().1,
-                _phantom: PhantomData,
-            })
-        } else {
-            Err(ConvertToOnlyNullError)
+impl<T> PartialOrd<Option<NonNull<T>>> for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Option<NonNull<T>>) -> Option<Ordering> {
+        match other {
+            Some(_) => Some(Ordering::Less),
+            None => Some(Ordering::Equal),
         }
     }
 }
 
-impl<T> TryFrom<*mut T> for OnlyNull<T> {
-    type Error = ConvertToOnlyNullError;
+impl<T> PartialOrd<OnlyNull<T>> for Option<NonNull<T>>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &OnlyNull<T>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl<T> From<OnlyNull<T>> for Option<NonNull<T>>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(_: OnlyNull<T>) -> Self {
+        None
+    }
+}
+
+impl<T> TryFrom<Option<NonNull<T>>> for OnlyNull<T>
+where
+    T: ?Sized + Pointee<Metadata = ()>,
+{
+    type Error = ConvertToOnlyNullError<NonNull<T>>;
 
-    fn try_from(ptr: *mut T) -> Result<Self, Self::Error> {
-        if ptr.is_null() {
-            Ok(Self {
-                meta: ptr.to_raw_parts().1,
-                _phantom: PhantomData,
-            })
-        } else {
-            Err(ConvertToOnlyNullError)
+    fn try_from(ptr: Option<NonNull<T>>) -> Result<Self, Self::Error> {
+        match ptr {
+            Some(ptr) => Err(ConvertToOnlyNullError { ptr }),
+            None => Ok(Self::null()),
         }
     }
 }
 
-/// An error type for converting to an [`OnlyNull`] pointer from a normal raw pointer.
+/// An error type for converting to an [`OnlyNull`] pointer from a normal pointer.
+///
+/// Since the pointer wasn't null, it's not thrown away, you can get it back with
+/// [`ConvertToOnlyNullError::into_inner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConvertToOnlyNullError<P> {
+    ptr: P,
+}
+
+impl<P> ConvertToOnlyNullError<P> {
+    /// Returns the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> P {
+        self.ptr
+    }
+}
+
+impl<T: ?Sized> ConvertToOnlyNullError<*const T> {
+    /// The address of the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.addr()
+    }
+}
+
+impl<T: ?Sized> ConvertToOnlyNullError<*mut T> {
+    /// The address of the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.addr()
+    }
+}
+
+impl<T: ?Sized> ConvertToOnlyNullError<NonNull<T>> {
+    /// The address of the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.addr().get()
+    }
+}
+
+impl<P: fmt::Pointer> fmt::Display for ConvertToOnlyNullError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pointer {:p} is not null", self.ptr)
+    }
+}
+
+impl<P: fmt::Debug + fmt::Pointer> core::error::Error for ConvertToOnlyNullError<P> {}
+
+/// An error type for converting to an [`OnlyAddr`] or [`OnlyDangling`] pointer from a normal
+/// pointer, when the pointer is at the wrong address.
+///
+/// Like [`ConvertToOnlyNullError`], you can get the pointer back with
+/// [`ConvertToOnlyAddrError::into_inner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConvertToOnlyAddrError<P> {
+    ptr: P,
+    expected: usize,
+}
+
+impl<P> ConvertToOnlyAddrError<P> {
+    pub(crate) fn new(ptr: P, expected: usize) -> Self {
+        Self { ptr, expected }
+    }
+
+    /// Returns the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> P {
+        self.ptr
+    }
+
+    /// The address the pointer was supposed to be at.
+    #[inline]
+    #[must_use]
+    pub fn expected_addr(&self) -> usize {
+        self.expected
+    }
+}
+
+impl<T: ?Sized> ConvertToOnlyAddrError<*const T> {
+    /// The address of the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.addr()
+    }
+}
+
+impl<T: ?Sized> ConvertToOnlyAddrError<*mut T> {
+    /// The address of the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.addr()
+    }
+}
+
+impl<T: ?Sized> ConvertToOnlyAddrError<NonNull<T>> {
+    /// The address of the pointer that failed to convert.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.addr().get()
+    }
+}
+
+impl<P: fmt::Pointer> fmt::Display for ConvertToOnlyAddrError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pointer {:p} is not at address {:#x}",
+            self.ptr, self.expected
+        )
+    }
+}
+
+impl<P: fmt::Debug + fmt::Pointer> core::error::Error for ConvertToOnlyAddrError<P> {}
+
+/// An error type for [`OnlyNull::cast_slice`], when the slice can't be evenly rescaled to the new
+/// element type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct ConvertToOnlyNullError;
+pub struct CastSliceError;
+
+impl fmt::Display for CastSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("slice size is not a multiple of the new element size")
+    }
+}
+
+impl core::error::Error for CastSliceError {}
+
+/// An error type for [`NullTerminated::from_slice`], when there is no null pointer in the slice to
+/// end it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MissingTerminatorError;
+
+impl fmt::Display for MissingTerminatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("slice has no null pointer to terminate it")
+    }
+}
+
+impl core::error::Error for MissingTerminatorError {}
@@ -0,0 +1,163 @@
+use core::fmt;
+use core::ptr::NonNull;
+
+use crate::{pointee, OnlyNull, Pointee};
+
+/// # `NullOr`
+///
+/// A pointer that is either statically known to be null, or statically known to not be null. It's
+/// like a raw pointer, except you have to check which one it is before you do anything with it,
+/// which is what you should've been doing anyway.
+///
+/// Since [`NonNull`] has a niche where the null pointer would be, this is the same size as the
+/// equivalent raw pointer.
+pub enum NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// The pointer is null.
+    Null(OnlyNull<T>),
+    /// The pointer is not null.
+    NonNull(NonNull<T>),
+}
+
+impl<T> NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Returns true if the pointer is null.
+    #[inline]
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null(_))
+    }
+
+    /// Returns the null pointer, if it is one.
+    #[inline]
+    #[must_use]
+    pub fn null(self) -> Option<OnlyNull<T>> {
+        match self {
+            Self::Null(ptr) => Some(ptr),
+            Self::NonNull(_) => None,
+        }
+    }
+
+    /// Returns the non-null pointer, if it is one.
+    #[inline]
+    #[must_use]
+    pub fn non_null(self) -> Option<NonNull<T>> {
+        match self {
+            Self::Null(_) => None,
+            Self::NonNull(ptr) => Some(ptr),
+        }
+    }
+
+    /// Returns the metadata of the pointer.
+    #[inline]
+    #[must_use]
+    pub fn metadata(self) -> T::Metadata {
+        match self {
+            Self::Null(ptr) => ptr.metadata(),
+            Self::NonNull(ptr) => pointee::metadata(ptr.as_ptr()),
+        }
+    }
+}
+
+impl<T> Clone for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NullOr<T> where T: ?Sized + Pointee {}
+
+impl<T> fmt::Debug for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null(ptr) => f.debug_tuple("Null").field(ptr).finish(),
+            Self::NonNull(ptr) => f.debug_tuple("NonNull").field(ptr).finish(),
+        }
+    }
+}
+
+impl<T> From<OnlyNull<T>> for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: OnlyNull<T>) -> Self {
+        Self::Null(ptr)
+    }
+}
+
+impl<T> From<NonNull<T>> for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: NonNull<T>) -> Self {
+        Self::NonNull(ptr)
+    }
+}
+
+impl<T> From<*const T> for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: *const T) -> Self {
+        Self::from(ptr.cast_mut())
+    }
+}
+
+impl<T> From<*mut T> for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: *mut T) -> Self {
+        match NonNull::new(ptr) {
+            Some(ptr) => Self::NonNull(ptr),
+            None => Self::Null(OnlyNull::from_metadata(pointee::metadata(ptr))),
+        }
+    }
+}
+
+impl<T> From<NullOr<T>> for *const T
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: NullOr<T>) -> Self {
+        <*mut T>::from(ptr).cast_const()
+    }
+}
+
+impl<T> From<NullOr<T>> for *mut T
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: NullOr<T>) -> Self {
+        match ptr {
+            NullOr::Null(ptr) => ptr.into(),
+            NullOr::NonNull(ptr) => ptr.as_ptr(),
+        }
+    }
+}
+
+impl<T> From<NullOr<T>> for Option<NonNull<T>>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: NullOr<T>) -> Self {
+        ptr.non_null()
+    }
+}
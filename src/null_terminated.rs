@@ -0,0 +1,263 @@
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::iter::FromIterator;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{MissingTerminatorError, OnlyNull, Pointee};
+
+/// # `NullTerminated`
+///
+/// An array of pointers that ends at the first null one, like `argv` and `environ`. Everything
+/// before the terminator is known not to be null, so this hands them out as [`NonNull`], both as an
+/// iterator and as a slice.
+///
+/// The end is found by trying to turn each element into an [`OnlyNull`], so the terminator keeps
+/// its metadata if there is any.
+pub struct NullTerminated<'a, T>
+where
+    T: ?Sized + Pointee,
+{
+    ptr: NonNull<*const T>,
+    _marker: PhantomData<&'a [*const T]>,
+}
+
+impl<'a, T> NullTerminated<'a, T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Wraps a raw null terminated array, such as the `argv` handed to `main` by C.
+    ///
+    /// # Safety
+    /// `ptr` has to point to an array of pointers that contains a null pointer, and every element
+    /// up to and including that one has to be readable and left alone for `'a`.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn from_ptr(ptr: NonNull<*const T>) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wraps the start of a slice, up to the first null pointer.
+    ///
+    /// # Errors
+    /// Fails if there is no null pointer in the slice.
+    pub fn from_slice(slice: &'a [*const T]) -> Result<Self, MissingTerminatorError> {
+        if slice.iter().any(|&ptr| OnlyNull::try_from(ptr).is_ok()) {
+            // SAFETY: The slice is borrowed for `'a`, and has a terminator.
+            Ok(unsafe { Self::from_ptr(NonNull::from(slice).cast()) })
+        } else {
+            Err(MissingTerminatorError)
+        }
+    }
+
+    /// Returns the pointer to the first element, to hand back to C.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns the number of pointers before the terminator.
+    ///
+    /// This has to walk the whole array to find the end.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.clone().count()
+    }
+
+    /// Returns true if the terminator comes first.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.clone().next().is_none()
+    }
+
+    /// Returns the pointers before the terminator as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &'a [NonNull<T>] {
+        // SAFETY: The first `len` elements are readable for `'a` and aren't null, and `NonNull<T>`
+        // has the same layout as `*const T`.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr().cast(), self.len()) }
+    }
+
+    /// Returns the terminator at the end of the array.
+    #[must_use]
+    pub fn terminator(&self) -> OnlyNull<T> {
+        let mut rest = self.clone();
+        rest.by_ref().for_each(drop);
+        match rest.read() {
+            Ok(terminator) => terminator,
+            // SAFETY: The iterator stops at the terminator.
+            Err(_) => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn read(&self) -> Result<OnlyNull<T>, NonNull<T>> {
+        // SAFETY: Everything up to the terminator is readable, and this never goes past it.
+        let ptr = unsafe { self.ptr.as_ptr().read() };
+        OnlyNull::try_from(ptr)
+            // SAFETY: It isn't null, or it would have converted.
+            .map_err(|err| unsafe { NonNull::new_unchecked(err.into_inner().cast_mut()) })
+    }
+}
+
+impl<T> Iterator for NullTerminated<'_, T>
+where
+    T: ?Sized + Pointee,
+{
+    type Item = NonNull<T>;
+
+    fn next(&mut self) -> Option<NonNull<T>> {
+        let ptr = self.read().err()?;
+        // SAFETY: The element wasn't the terminator, so there's at least one more.
+        self.ptr = unsafe { self.ptr.add(1) };
+        Some(ptr)
+    }
+}
+
+impl<T> FusedIterator for NullTerminated<'_, T> where T: ?Sized + Pointee {}
+
+impl<T> Clone for NullTerminated<'_, T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for NullTerminated<'_, T>
+where
+    T: ?Sized + Pointee,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// # `NullTerminatedBuilder`
+///
+/// Builds a [`NullTerminated`] array, keeping the [`OnlyNull`] terminator at the end as pointers
+/// are pushed.
+#[cfg(feature = "alloc")]
+pub struct NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee,
+{
+    ptrs: Vec<*const T>,
+    terminator: OnlyNull<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee<Metadata = ()>,
+{
+    /// Creates an empty array, holding only the terminator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_terminator(OnlyNull::null())
+    }
+
+    /// Creates an array holding the pointers in `slice`, followed by the terminator.
+    #[must_use]
+    pub fn from_slice(slice: &[NonNull<T>]) -> Self {
+        let mut builder = Self::new();
+        builder.extend(slice.iter().copied());
+        builder
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Creates an empty array, ended by `terminator`. This is how to build an array of unsized
+    /// pointers, where the terminator needs metadata too.
+    #[must_use]
+    pub fn with_terminator(terminator: OnlyNull<T>) -> Self {
+        Self {
+            ptrs: alloc::vec![terminator.as_ptr()],
+            terminator,
+        }
+    }
+
+    /// Adds a pointer before the terminator.
+    pub fn push(&mut self, ptr: NonNull<T>) {
+        let last = self.ptrs.len() - 1;
+        self.ptrs[last] = ptr.as_ptr();
+        self.ptrs.push(self.terminator.as_ptr());
+    }
+
+    /// Borrows the array built so far.
+    #[must_use]
+    pub fn as_null_terminated(&self) -> NullTerminated<'_, T> {
+        // SAFETY: The array always ends in the terminator, and is borrowed for as long as it's
+        // used.
+        unsafe { NullTerminated::from_ptr(NonNull::from(&*self.ptrs).cast()) }
+    }
+
+    /// Returns the array, terminator included.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<*const T> {
+        self.ptrs
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee<Metadata = ()>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Extend<NonNull<T>> for NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn extend<I: IntoIterator<Item = NonNull<T>>>(&mut self, iter: I) {
+        for ptr in iter {
+            self.push(ptr);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FromIterator<NonNull<T>> for NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee<Metadata = ()>,
+{
+    fn from_iter<I: IntoIterator<Item = NonNull<T>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for NullTerminatedBuilder<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_null_terminated(), f)
+    }
+}
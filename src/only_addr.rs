@@ -0,0 +1,115 @@
+use core::marker::PhantomData;
+
+use crate::{pointee, ConvertToOnlyAddrError, OnlyNull, Pointee};
+
+/// # `OnlyAddr`
+///
+/// [`OnlyNull`], but for any address you like, as long as you decide at compile time. Useful for
+/// memory mapped registers, or for tests that want a pointer they can recognize.
+///
+/// An `OnlyAddr<T, 0>` is the same thing as an [`OnlyNull<T>`], and converts to and from one.
+pub struct OnlyAddr<T, const ADDR: usize>
+where
+    T: ?Sized + Pointee,
+{
+    meta: T::Metadata,
+    _phantom: PhantomData<*const T>,
+}
+
+metadata_pointer!(OnlyAddr<T, const ADDR: usize>, ConvertToOnlyAddrError);
+
+impl<T, const ADDR: usize> OnlyAddr<T, ADDR>
+where
+    T: ?Sized + Pointee<Metadata = ()>,
+{
+    /// The pointer.
+    pub const NEW: Self = Self::new();
+
+    /// Creates the pointer.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_metadata(())
+    }
+}
+
+impl<T, const ADDR: usize> OnlyAddr<T, ADDR>
+where
+    T: ?Sized + Pointee,
+{
+    /// Returns the address of the pointer, which is `ADDR`.
+    #[inline]
+    #[must_use]
+    pub const fn addr(self) -> usize {
+        ADDR
+    }
+
+    nightly_const! {
+        /// Converts to a raw pointer with the same metadata.
+        ///
+        /// The pointer has no provenance, so it can't be used to access memory unless the address
+        /// is exposed by other means, like memory mapped registers are.
+        #[inline]
+        #[must_use]
+        pub fn as_ptr(self) -> *const T {
+            pointee::from_raw_parts(core::ptr::without_provenance::<()>(ADDR), self.meta)
+        }
+
+        /// Converts to a mutable raw pointer with the same metadata, see [`OnlyAddr::as_ptr`].
+        #[inline]
+        #[must_use]
+        pub fn as_mut_ptr(self) -> *mut T {
+            pointee::from_raw_parts_mut(core::ptr::without_provenance_mut::<()>(ADDR), self.meta)
+        }
+    }
+
+    /// Converts from a raw pointer, if it's at `ADDR`.
+    ///
+    /// Unlike [`OnlyNull::from_ptr`] this can't be used in constant expressions, even with the
+    /// `nightly` feature. Whether a pointer is null is known while evaluating them, but not its
+    /// address.
+    ///
+    /// # Errors
+    /// Fails if the pointer is somewhere else, returning it in the error.
+    #[inline]
+    pub fn from_ptr(ptr: *const T) -> Result<Self, ConvertToOnlyAddrError<*const T>> {
+        if ptr.addr() == ADDR {
+            Ok(Self::from_metadata(pointee::metadata(ptr)))
+        } else {
+            Err(ConvertToOnlyAddrError::new(ptr, ADDR))
+        }
+    }
+
+    /// Converts from a mutable raw pointer, if it's at `ADDR`, see [`OnlyAddr::from_ptr`].
+    ///
+    /// # Errors
+    /// Fails if the pointer is somewhere else, returning it in the error.
+    #[inline]
+    pub fn from_mut_ptr(ptr: *mut T) -> Result<Self, ConvertToOnlyAddrError<*mut T>> {
+        if ptr.addr() == ADDR {
+            Ok(Self::from_metadata(pointee::metadata(ptr)))
+        } else {
+            Err(ConvertToOnlyAddrError::new(ptr, ADDR))
+        }
+    }
+}
+
+impl<T> From<OnlyNull<T>> for OnlyAddr<T, 0>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: OnlyNull<T>) -> Self {
+        Self::from_metadata(ptr.metadata())
+    }
+}
+
+impl<T> From<OnlyAddr<T, 0>> for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: OnlyAddr<T, 0>) -> Self {
+        Self::from_metadata(ptr.metadata())
+    }
+}
@@ -0,0 +1,147 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use crate::{formatting, ConvertToOnlyAddrError};
+
+/// # `OnlyDangling`
+///
+/// The other pointer everyone uses when they don't have anything to point to. It is always
+/// [`NonNull::dangling`], which is to say its address is always `align_of::<T>()`. Unlike
+/// [`OnlyNull`](crate::OnlyNull) it isn't null, so it converts to a [`NonNull`] without any fuss.
+///
+/// This would be an [`OnlyAddr`](crate::OnlyAddr) with `ADDR = align_of::<T>()`, but const
+/// generics can't depend on `T` like that, so it's its own type.
+pub struct OnlyDangling<T> {
+    _phantom: PhantomData<*const T>,
+}
+
+// SAFETY: The pointer can never be dereferenced, there is nothing there.
+unsafe impl<T> Send for OnlyDangling<T> {}
+// SAFETY: See above.
+unsafe impl<T> Sync for OnlyDangling<T> {}
+
+impl<T> OnlyDangling<T> {
+    /// The dangling pointer.
+    pub const DANGLING: Self = Self::dangling();
+
+    /// Creates a dangling pointer.
+    #[inline]
+    #[must_use]
+    pub const fn dangling() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the address of the pointer, which is the alignment of `T`.
+    #[inline]
+    #[must_use]
+    pub const fn addr(self) -> usize {
+        core::mem::align_of::<T>()
+    }
+
+    /// Converts to a [`NonNull`].
+    #[inline]
+    #[must_use]
+    pub const fn as_non_null(self) -> NonNull<T> {
+        NonNull::dangling()
+    }
+
+    /// Converts to a raw pointer.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(self) -> *const T {
+        self.as_non_null().as_ptr().cast_const()
+    }
+
+    /// Converts to a mutable raw pointer.
+    #[inline]
+    #[must_use]
+    pub const fn as_mut_ptr(self) -> *mut T {
+        self.as_non_null().as_ptr()
+    }
+
+    /// Converts from a raw pointer, if it's dangling.
+    ///
+    /// # Errors
+    /// Fails if the pointer is somewhere else, returning it in the error.
+    #[inline]
+    pub fn from_ptr(ptr: *const T) -> Result<Self, ConvertToOnlyAddrError<*const T>> {
+        if ptr.addr() == core::mem::align_of::<T>() {
+            Ok(Self::dangling())
+        } else {
+            Err(ConvertToOnlyAddrError::new(ptr, core::mem::align_of::<T>()))
+        }
+    }
+
+    /// Casts to a dangling pointer of another type.
+    ///
+    /// Note that the address changes if `U` has a different alignment than `T`.
+    #[inline]
+    #[must_use]
+    pub const fn cast<U>(self) -> OnlyDangling<U> {
+        OnlyDangling::dangling()
+    }
+}
+
+impl<T> fmt::Debug for OnlyDangling<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatting::debug_pointer::<T>(f, "OnlyDangling", self.addr(), ())
+    }
+}
+
+pointer_traits! {
+    impl[T] OnlyDangling<T> where [],
+    key: |_ptr| (),
+    pointer: as_ptr,
+    default where []: Self::dangling(),
+}
+
+impl<T> From<OnlyDangling<T>> for NonNull<T> {
+    #[inline]
+    fn from(ptr: OnlyDangling<T>) -> Self {
+        ptr.as_non_null()
+    }
+}
+
+impl<T> From<OnlyDangling<T>> for *const T {
+    #[inline]
+    fn from(ptr: OnlyDangling<T>) -> Self {
+        ptr.as_ptr()
+    }
+}
+
+impl<T> From<OnlyDangling<T>> for *mut T {
+    #[inline]
+    fn from(ptr: OnlyDangling<T>) -> Self {
+        ptr.as_mut_ptr()
+    }
+}
+
+impl<T> TryFrom<*const T> for OnlyDangling<T> {
+    type Error = ConvertToOnlyAddrError<*const T>;
+
+    fn try_from(ptr: *const T) -> Result<Self, Self::Error> {
+        Self::from_ptr(ptr)
+    }
+}
+
+impl<T> TryFrom<*mut T> for OnlyDangling<T> {
+    type Error = ConvertToOnlyAddrError<*mut T>;
+
+    fn try_from(ptr: *mut T) -> Result<Self, Self::Error> {
+        Self::from_ptr(ptr.cast_const())
+            .map_err(|_| ConvertToOnlyAddrError::new(ptr, core::mem::align_of::<T>()))
+    }
+}
+
+impl<T> TryFrom<NonNull<T>> for OnlyDangling<T> {
+    type Error = ConvertToOnlyAddrError<NonNull<T>>;
+
+    fn try_from(ptr: NonNull<T>) -> Result<Self, Self::Error> {
+        Self::from_ptr(ptr.as_ptr())
+            .map_err(|_| ConvertToOnlyAddrError::new(ptr, core::mem::align_of::<T>()))
+    }
+}
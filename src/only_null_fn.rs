@@ -0,0 +1,187 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{formatting, ConvertToOnlyNullError, OnlyNull};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A function pointer type, like `fn(u8) -> bool` or `unsafe extern "C" fn(*mut c_void)`.
+///
+/// This is implemented for safe and unsafe functions with the Rust and C ABIs, taking up to twelve
+/// arguments. Function pointers with arguments that borrow, like `fn(&u8)`, are generic over the
+/// lifetime and aren't covered.
+pub trait FnPtr: Copy + fmt::Debug + fmt::Pointer + sealed::Sealed {
+    /// Returns the address of the function.
+    fn as_ptr(self) -> *const ();
+}
+
+macro_rules! fn_ptr_impls {
+    ($($arg:ident)*) => {
+        fn_ptr_impls!(@abi [$($arg)*] fn);
+        fn_ptr_impls!(@abi [$($arg)*] unsafe fn);
+        fn_ptr_impls!(@abi [$($arg)*] extern "C" fn);
+        fn_ptr_impls!(@abi [$($arg)*] unsafe extern "C" fn);
+    };
+    (@abi [$($arg:ident)*] $($fn:tt)*) => {
+        impl<R, $($arg),*> sealed::Sealed for $($fn)*($($arg),*) -> R {}
+
+        impl<R, $($arg),*> FnPtr for $($fn)*($($arg),*) -> R {
+            #[inline]
+            fn as_ptr(self) -> *const () {
+                self as *const ()
+            }
+        }
+    };
+}
+
+fn_ptr_impls!();
+fn_ptr_impls!(A);
+fn_ptr_impls!(A B);
+fn_ptr_impls!(A B C);
+fn_ptr_impls!(A B C D);
+fn_ptr_impls!(A B C D E);
+fn_ptr_impls!(A B C D E F);
+fn_ptr_impls!(A B C D E F G);
+fn_ptr_impls!(A B C D E F G H);
+fn_ptr_impls!(A B C D E F G H I);
+fn_ptr_impls!(A B C D E F G H I J);
+fn_ptr_impls!(A B C D E F G H I J K);
+fn_ptr_impls!(A B C D E F G H I J K L);
+
+/// # `OnlyNullFn`
+///
+/// An [`OnlyNull`] for function pointers, which aren't allowed to be null at all. The closest Rust
+/// gets is `Option<F>`, which is what C callbacks are usually written as, so this turns into
+/// [`None`]. It is the callback that was never registered, and never will be.
+///
+/// Callback slots tend to come back from C as plain addresses, so it can be checked from a
+/// `*const ()` or a `usize` too.
+pub struct OnlyNullFn<F>
+where
+    F: FnPtr,
+{
+    _phantom: PhantomData<F>,
+}
+
+impl<F> OnlyNullFn<F>
+where
+    F: FnPtr,
+{
+    /// The null function pointer.
+    pub const NULL: Self = Self::null();
+
+    /// Creates a null function pointer.
+    #[inline]
+    #[must_use]
+    pub const fn null() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the address of the function, which is null.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(self) -> *const () {
+        core::ptr::null()
+    }
+
+    /// Returns the function pointer as an [`Option`], which is [`None`].
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Option<F> {
+        None
+    }
+
+    /// Checks a callback slot holding the address of a function.
+    ///
+    /// # Errors
+    /// Fails if the pointer isn't null, returning it in the error.
+    #[inline]
+    pub fn from_ptr(ptr: *const ()) -> Result<Self, ConvertToOnlyNullError<*const ()>> {
+        OnlyNull::from_ptr(ptr).map(|_| Self::null())
+    }
+
+    /// Checks a callback slot holding the address of a function as an integer.
+    ///
+    /// # Errors
+    /// Fails if the address isn't zero, returning it as a pointer in the error.
+    #[inline]
+    pub fn from_addr(addr: usize) -> Result<Self, ConvertToOnlyNullError<*const ()>> {
+        Self::from_ptr(core::ptr::without_provenance(addr))
+    }
+
+    /// Casts to another function pointer type.
+    #[inline]
+    #[must_use]
+    pub const fn cast<G>(self) -> OnlyNullFn<G>
+    where
+        G: FnPtr,
+    {
+        OnlyNullFn::null()
+    }
+}
+
+impl<F> fmt::Debug for OnlyNullFn<F>
+where
+    F: FnPtr,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatting::debug_pointer::<F>(f, "OnlyNullFn", 0, ())
+    }
+}
+
+pointer_traits! {
+    impl[F] OnlyNullFn<F> where [F: FnPtr],
+    key: |_ptr| (),
+    pointer: as_ptr,
+    default where [F: FnPtr]: Self::null(),
+}
+
+impl<F> From<OnlyNullFn<F>> for Option<F>
+where
+    F: FnPtr,
+{
+    #[inline]
+    fn from(ptr: OnlyNullFn<F>) -> Self {
+        ptr.get()
+    }
+}
+
+impl<F> From<OnlyNullFn<F>> for *const ()
+where
+    F: FnPtr,
+{
+    #[inline]
+    fn from(ptr: OnlyNullFn<F>) -> Self {
+        ptr.as_ptr()
+    }
+}
+
+impl<F> TryFrom<Option<F>> for OnlyNullFn<F>
+where
+    F: FnPtr,
+{
+    type Error = ConvertToOnlyNullError<F>;
+
+    fn try_from(ptr: Option<F>) -> Result<Self, Self::Error> {
+        match ptr {
+            Some(ptr) => Err(ConvertToOnlyNullError { ptr }),
+            None => Ok(Self::null()),
+        }
+    }
+}
+
+impl<F> TryFrom<*const ()> for OnlyNullFn<F>
+where
+    F: FnPtr,
+{
+    type Error = ConvertToOnlyNullError<*const ()>;
+
+    fn try_from(ptr: *const ()) -> Result<Self, Self::Error> {
+        Self::from_ptr(ptr)
+    }
+}
@@ -0,0 +1,173 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{formatting, ConvertToOnlyNullError, OnlyNull, Pointee};
+
+/// # `OnlyNullMut`
+///
+/// The mutable version of [`OnlyNull`]. It is exactly as null, but it only converts to `*mut T`.
+/// You can mutate through it exactly as much as you can read through an [`OnlyNull`].
+///
+/// Like `*mut T` it is invariant in `T`, so it can't be shortened to a pointer to a shorter lived
+/// type. Use [`OnlyNullMut::cast`] for that.
+/// ```compile_fail
+/// use packed::OnlyNullMut;
+///
+/// fn shorten<'a>(ptr: OnlyNullMut<&'static u8>) -> OnlyNullMut<&'a u8> {
+///     ptr
+/// }
+/// ```
+/// ```compile_fail
+/// use packed::OnlyNullMut;
+///
+/// fn lengthen<'a>(ptr: OnlyNullMut<&'a u8>) -> OnlyNullMut<&'static u8> {
+///     ptr
+/// }
+/// ```
+pub struct OnlyNullMut<T>
+where
+    T: ?Sized + Pointee,
+{
+    inner: OnlyNull<T>,
+    _invariant: PhantomData<*mut T>,
+}
+
+// SAFETY: The pointer can never be dereferenced, and the metadata is always `Send` and `Sync`.
+unsafe impl<T> Send for OnlyNullMut<T> where T: ?Sized + Pointee {}
+// SAFETY: See above.
+unsafe impl<T> Sync for OnlyNullMut<T> where T: ?Sized + Pointee {}
+
+impl<T> OnlyNullMut<T>
+where
+    T: ?Sized + Pointee<Metadata = ()>,
+{
+    /// The null pointer.
+    pub const NULL: Self = Self::null();
+
+    /// Creates a null pointer
+    #[inline]
+    #[must_use]
+    pub const fn null() -> Self {
+        Self::from_metadata(())
+    }
+}
+
+impl<T> OnlyNullMut<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Creates a null pointer carrying the given metadata.
+    #[inline]
+    #[must_use]
+    pub const fn from_metadata(meta: T::Metadata) -> Self {
+        OnlyNull::from_metadata(meta).cast_mut()
+    }
+
+    /// Returns the metadata of the pointer.
+    #[inline]
+    #[must_use]
+    pub const fn metadata(self) -> T::Metadata {
+        self.inner.metadata()
+    }
+
+    nightly_const! {
+        /// Converts to a mutable raw null pointer with the same metadata.
+        #[inline]
+        #[must_use]
+        pub fn as_mut_ptr(self) -> *mut T {
+            self.inner.as_mut_ptr()
+        }
+
+        /// Converts from a mutable raw pointer, if it's null.
+        ///
+        /// # Errors
+        /// Fails if the pointer isn't null, returning it in the error.
+        #[inline]
+        pub fn from_mut_ptr(ptr: *mut T) -> Result<Self, ConvertToOnlyNullError<*mut T>> {
+            match OnlyNull::from_mut_ptr(ptr) {
+                Ok(ptr) => Ok(ptr.cast_mut()),
+                Err(err) => Err(err),
+            }
+        }
+    }
+
+    /// Forgets that the pointer was mutable.
+    #[inline]
+    #[must_use]
+    pub const fn cast_const(self) -> OnlyNull<T> {
+        self.inner
+    }
+
+    /// Casts to a pointer of another type.
+    #[inline]
+    #[must_use]
+    pub const fn cast<U>(self) -> OnlyNullMut<U>
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>,
+    {
+        self.inner.cast().cast_mut()
+    }
+}
+
+impl<T> OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Converts to a mutable null pointer.
+    #[inline]
+    #[must_use]
+    pub const fn cast_mut(self) -> OnlyNullMut<T> {
+        OnlyNullMut {
+            inner: self,
+            _invariant: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for OnlyNullMut<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatting::debug_pointer::<T>(f, "OnlyNullMut", 0, self.metadata())
+    }
+}
+
+pointer_traits! {
+    impl[T] OnlyNullMut<T> where [T: ?Sized + Pointee],
+    key: |ptr| ptr.inner,
+    pointer: as_mut_ptr,
+    default where [T: ?Sized + Pointee, T::Metadata: Default]: OnlyNull::default().cast_mut(),
+}
+
+impl<T> From<OnlyNullMut<T>> for *mut T
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: OnlyNullMut<T>) -> Self {
+        ptr.as_mut_ptr()
+    }
+}
+
+impl<T> From<OnlyNullMut<T>> for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: OnlyNullMut<T>) -> Self {
+        ptr.cast_const()
+    }
+}
+
+impl<T> TryFrom<*mut T> for OnlyNullMut<T>
+where
+    T: ?Sized + Pointee,
+{
+    type Error = ConvertToOnlyNullError<*mut T>;
+
+    fn try_from(ptr: *mut T) -> Result<Self, Self::Error> {
+        Self::from_mut_ptr(ptr)
+    }
+}
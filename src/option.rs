@@ -0,0 +1,81 @@
+//! Conversions between [`OnlyNull`] and the optional smart pointers and references. An
+//! [`OnlyNull`] always turns into [`None`], and only [`None`] turns back into an [`OnlyNull`].
+
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+use crate::{ConvertToOnlyNullError, OnlyNull, Pointee};
+
+impl<T> OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// Returns [`None`] of some pointer type to `T`, such as `Box<T>` or `&T`.
+    ///
+    /// This is the same as [`Into::into`], but you get to name the pointer type, which is handy
+    /// for unsized pointees where you had to build the [`OnlyNull`] with metadata first.
+    /// ```
+    /// use packed::OnlyNull;
+    ///
+    /// let none = OnlyNull::<[u8]>::null_slice(3).none_of::<&[u8]>();
+    /// assert!(none.is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn none_of<P>(self) -> Option<P>
+    where
+        Option<P>: From<Self>,
+    {
+        self.into()
+    }
+}
+
+macro_rules! option_conversions {
+    ($(
+        $(#[$attr:meta])*
+        impl<$($lt:lifetime)?> $ptr:ty;
+    )*) => {$(
+        $(#[$attr])*
+        impl<$($lt,)? T> From<OnlyNull<T>> for Option<$ptr>
+        where
+            T: ?Sized + Pointee,
+        {
+            #[inline]
+            fn from(_: OnlyNull<T>) -> Self {
+                None
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($lt,)? T> TryFrom<Option<$ptr>> for OnlyNull<T>
+        where
+            T: Pointee<Metadata = ()>,
+        {
+            type Error = ConvertToOnlyNullError<$ptr>;
+
+            fn try_from(ptr: Option<$ptr>) -> Result<Self, Self::Error> {
+                match ptr {
+                    Some(ptr) => Err(ConvertToOnlyNullError { ptr }),
+                    None => Ok(Self::null()),
+                }
+            }
+        }
+    )*};
+}
+
+option_conversions! {
+    impl<'a> &'a T;
+    impl<'a> &'a mut T;
+    #[cfg(feature = "alloc")]
+    impl<> Box<T>;
+    #[cfg(feature = "alloc")]
+    impl<> Rc<T>;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    impl<> Arc<T>;
+}
@@ -0,0 +1,466 @@
+//! The `Pointee` trait, and the functions for building and taking apart pointers with it.
+//!
+//! With the `nightly` feature this is all just `core::ptr`. Without it, there is a crate-local
+//! [`Pointee`] trait that does the same job on stable. It's implemented for every sized type, for
+//! slices and for `str`. Trait objects have to be registered with
+//! [`dyn_pointee!`](crate::dyn_pointee), since there's no way to implement anything for every
+//! `dyn Trait` at once.
+
+#[cfg(feature = "nightly")]
+pub use core::ptr::DynMetadata;
+
+#[cfg(feature = "nightly")]
+use crate::formatting::FmtMetadata;
+
+/// # `Pointee`
+///
+/// `core::ptr::Pointee`, for every type whose metadata this crate knows how to print, which is
+/// every type there is. The crate's own `Pointee` on stable has the same bound.
+#[cfg(feature = "nightly")]
+pub trait Pointee: core::ptr::Pointee<Metadata: FmtMetadata> {}
+
+#[cfg(feature = "nightly")]
+impl<T> Pointee for T where T: ?Sized + core::ptr::Pointee<Metadata: FmtMetadata> {}
+
+#[cfg(not(feature = "nightly"))]
+use self::polyfill::PointeeParts;
+#[cfg(not(feature = "nightly"))]
+pub use self::polyfill::{DynMetadata, Pointee};
+
+/// Returns the metadata of a pointer.
+#[cfg(feature = "nightly")]
+#[inline]
+pub(crate) const fn metadata<T>(ptr: *const T) -> T::Metadata
+where
+    T: ?Sized + Pointee,
+{
+    core::ptr::metadata(ptr)
+}
+
+/// Builds a pointer out of an address and metadata.
+#[cfg(feature = "nightly")]
+#[inline]
+pub(crate) const fn from_raw_parts<T>(data: *const (), meta: T::Metadata) -> *const T
+where
+    T: ?Sized + Pointee,
+{
+    core::ptr::from_raw_parts(data, meta)
+}
+
+/// Builds a mutable pointer out of an address and metadata.
+#[cfg(feature = "nightly")]
+#[inline]
+pub(crate) const fn from_raw_parts_mut<T>(data: *mut (), meta: T::Metadata) -> *mut T
+where
+    T: ?Sized + Pointee,
+{
+    core::ptr::from_raw_parts_mut(data, meta)
+}
+
+/// Returns the metadata of a pointer.
+#[cfg(not(feature = "nightly"))]
+#[inline]
+pub(crate) fn metadata<T>(ptr: *const T) -> T::Metadata
+where
+    T: ?Sized + Pointee,
+{
+    <T as PointeeParts<T::Metadata>>::metadata(ptr)
+}
+
+/// Builds a pointer out of an address and metadata.
+#[cfg(not(feature = "nightly"))]
+#[inline]
+pub(crate) fn from_raw_parts<T>(data: *const (), meta: T::Metadata) -> *const T
+where
+    T: ?Sized + Pointee,
+{
+    <T as PointeeParts<T::Metadata>>::from_raw_parts(data, meta)
+}
+
+/// Builds a mutable pointer out of an address and metadata.
+#[cfg(not(feature = "nightly"))]
+#[inline]
+pub(crate) fn from_raw_parts_mut<T>(data: *mut (), meta: T::Metadata) -> *mut T
+where
+    T: ?Sized + Pointee,
+{
+    from_raw_parts::<T>(data.cast_const(), meta).cast_mut()
+}
+
+/// Returns the address of the vtable in the metadata of a trait object pointer.
+#[cfg(feature = "nightly")]
+pub(crate) fn vtable_addr<Dyn>(meta: DynMetadata<Dyn>) -> usize
+where
+    Dyn: ?Sized + core::ptr::Pointee<Metadata = DynMetadata<Dyn>>,
+{
+    let ptr = core::ptr::from_raw_parts::<Dyn>(core::ptr::null::<()>(), meta);
+    // SAFETY: Only trait objects have `DynMetadata`.
+    let [_, vtable] = unsafe { Repr::split(ptr) };
+    vtable.addr()
+}
+
+// There is no way to take apart a trait object pointer on stable, or to get the vtable address out
+// of `DynMetadata` on nightly, so this relies on them being laid out as the address followed by
+// the vtable, the same as `core` does. The size is checked at compile time, and the order every
+// time a pointer is taken apart.
+#[repr(C)]
+union Repr<Dyn: ?Sized> {
+    ptr: *const Dyn,
+    parts: [*const (); 2],
+}
+
+impl<Dyn: ?Sized> Repr<Dyn> {
+    // Stops anything that isn't a pair of pointers from getting near the union.
+    const IS_WIDE: () = assert!(
+        core::mem::size_of::<*const Dyn>() == 2 * core::mem::size_of::<*const ()>(),
+        "only trait object pointers can be taken apart"
+    );
+
+    /// Splits a trait object pointer into its address and vtable.
+    ///
+    /// # Safety
+    /// `Dyn` has to be a trait object.
+    #[inline]
+    unsafe fn split(ptr: *const Dyn) -> [*const (); 2] {
+        let () = Self::IS_WIDE;
+        // SAFETY: `IS_WIDE` checked that both fields are the same size, and the caller promised
+        // this is a trait object pointer, which is a pair of plain pointers.
+        let [data, vtable] = unsafe { Self { ptr }.parts };
+        assert_eq!(
+            data,
+            ptr.cast::<()>(),
+            "trait object pointers don't start with the data address"
+        );
+        [data, vtable]
+    }
+
+    /// Builds a trait object pointer out of an address and a vtable.
+    ///
+    /// # Safety
+    /// `Dyn` has to be a trait object, and `vtable` one of its vtables.
+    #[cfg(not(feature = "nightly"))]
+    #[inline]
+    unsafe fn join(data: *const (), vtable: *const ()) -> *const Dyn {
+        let () = Self::IS_WIDE;
+        // SAFETY: See `split`.
+        unsafe {
+            Self {
+                parts: [data, vtable],
+            }
+            .ptr
+        }
+    }
+}
+
+/// Registers a trait object type as a [`Pointee`], so that it can be used with [`OnlyNull`]
+/// and friends on stable.
+///
+/// With the `nightly` feature every type is already a pointee, so this does nothing.
+///
+/// Trait objects can only be registered by the crate that defines the trait, or by this one. The
+/// ones from `core` are registered already: `Any`, `Debug`, `Display`, `fmt::Write`, `Error`,
+/// `Hasher`, the `Iterator` traits, `Future`, and `Fn`, `FnMut` and `FnOnce` with up to twelve
+/// arguments that don't borrow. Each also comes with `Send` and `Send + Sync` added. Trait objects
+/// of other crates' traits, like `std::io::Write`, need the `nightly` feature.
+///
+/// Only trait object types are accepted, written starting with `dyn`. Generic parameters go in an
+/// `impl<..>` in front, which is also how to cover every lifetime instead of only `'static`.
+///
+/// ```
+/// use core::convert::TryFrom;
+///
+/// trait Plugin {}
+///
+/// impl Plugin for u8 {}
+///
+/// packed::dyn_pointee!(impl<'a> dyn Plugin + 'a);
+///
+/// let ptr = core::ptr::null::<u8>() as *const dyn Plugin;
+/// assert!(packed::OnlyNull::try_from(ptr).is_ok());
+/// ```
+///
+/// Other unsized types can't be registered, since their metadata isn't a vtable.
+/// ```compile_fail
+/// struct Dst {
+///     _x: [u8],
+/// }
+///
+/// packed::dyn_pointee!(Dst);
+/// ```
+///
+/// [`OnlyNull`]: crate::OnlyNull
+#[cfg(not(feature = "nightly"))]
+#[macro_export]
+macro_rules! dyn_pointee {
+    ($(impl<$($gen:tt),+>)? dyn $($bounds:tt)+) => {
+        // SAFETY: The type is written with `dyn`, so it's a trait object.
+        unsafe impl$(<$($gen),+>)? $crate::__private::DynPointee for dyn $($bounds)+ {}
+
+        impl$(<$($gen),+>)? $crate::Pointee for dyn $($bounds)+ {
+            type Metadata = $crate::DynMetadata<dyn $($bounds)+>;
+        }
+    };
+}
+
+/// Registers a trait object type as a [`Pointee`], so that it can be used with [`OnlyNull`]
+/// and friends on stable.
+///
+/// With the `nightly` feature every type is already a pointee, so this does nothing. It still only
+/// accepts trait objects, so that code written for one backend builds with the other.
+///
+/// [`OnlyNull`]: crate::OnlyNull
+#[cfg(feature = "nightly")]
+#[macro_export]
+macro_rules! dyn_pointee {
+    ($(impl<$($gen:tt),+>)? dyn $($bounds:tt)+) => {};
+}
+
+#[cfg(not(feature = "nightly"))]
+pub(crate) mod polyfill {
+    use core::cmp::Ordering;
+    use core::fmt;
+    use core::hash::{Hash, Hasher};
+    use core::marker::PhantomData;
+    use core::ptr::NonNull;
+
+    use super::Repr;
+    use crate::formatting::FmtMetadata;
+
+    /// # `Pointee`
+    ///
+    /// A stand-in for `core::ptr::Pointee`, which isn't stable. It says what metadata a pointer to
+    /// `Self` carries, and nothing else, same as the real one.
+    ///
+    /// This is implemented for all sized types, slices and `str`. Use
+    /// [`dyn_pointee!`](crate::dyn_pointee) for trait objects.
+    pub trait Pointee: PointeeParts<<Self as Pointee>::Metadata> {
+        /// The metadata of a pointer to `Self`.
+        type Metadata: Copy + Send + Sync + Ord + Hash + Unpin + fmt::Debug + FmtMetadata;
+    }
+
+    /// How to put a pointer to `Self` together and take it apart again. This is kept apart from
+    /// [`Pointee`] so that the public trait looks the same whichever backend is in use.
+    ///
+    /// # Safety
+    /// `from_raw_parts` has to return a pointer with the given address and metadata, and
+    /// `metadata` has to return the metadata of the pointer it's given.
+    #[doc(hidden)]
+    pub unsafe trait PointeeParts<M> {
+        fn metadata(ptr: *const Self) -> M;
+
+        fn from_raw_parts(data: *const (), meta: M) -> *const Self;
+    }
+
+    /// Marks a trait object type, for [`dyn_pointee!`](crate::dyn_pointee).
+    ///
+    /// # Safety
+    /// `Self` has to be a trait object.
+    #[doc(hidden)]
+    pub unsafe trait DynPointee {}
+
+    impl<T> Pointee for T {
+        type Metadata = ();
+    }
+
+    unsafe impl<T> PointeeParts<()> for T {
+        #[inline]
+        fn metadata(_: *const T) {}
+
+        #[inline]
+        fn from_raw_parts(data: *const (), (): ()) -> *const T {
+            data.cast()
+        }
+    }
+
+    impl<T> Pointee for [T] {
+        type Metadata = usize;
+    }
+
+    unsafe impl<T> PointeeParts<usize> for [T] {
+        #[inline]
+        fn metadata(ptr: *const [T]) -> usize {
+            ptr.len()
+        }
+
+        #[inline]
+        fn from_raw_parts(data: *const (), len: usize) -> *const [T] {
+            core::ptr::slice_from_raw_parts(data.cast(), len)
+        }
+    }
+
+    impl Pointee for str {
+        type Metadata = usize;
+    }
+
+    unsafe impl PointeeParts<usize> for str {
+        #[inline]
+        fn metadata(ptr: *const str) -> usize {
+            (ptr as *const [u8]).len()
+        }
+
+        #[inline]
+        fn from_raw_parts(data: *const (), len: usize) -> *const str {
+            core::ptr::slice_from_raw_parts(data.cast::<u8>(), len) as *const str
+        }
+    }
+
+    unsafe impl<Dyn> PointeeParts<DynMetadata<Dyn>> for Dyn
+    where
+        Dyn: ?Sized + DynPointee,
+    {
+        #[inline]
+        fn metadata(ptr: *const Dyn) -> DynMetadata<Dyn> {
+            // SAFETY: `DynPointee` is only for trait objects.
+            unsafe { DynMetadata::new(ptr) }
+        }
+
+        #[inline]
+        fn from_raw_parts(data: *const (), meta: DynMetadata<Dyn>) -> *const Dyn {
+            // SAFETY: See above.
+            unsafe { meta.with_data(data) }
+        }
+    }
+
+    // Only the crate that defines a trait can register its trait objects, besides this one, so the
+    // ones from `core` are registered here. Everything is covered for any lifetime, and with `Send`
+    // and `Send + Sync` added.
+    macro_rules! core_dyn_pointees {
+        (impl<$($gen:ident),+> $($bounds:tt)+) => {
+            crate::dyn_pointee!(impl<'a, $($gen),+> dyn $($bounds)+ + 'a);
+            crate::dyn_pointee!(impl<'a, $($gen),+> dyn $($bounds)+ + Send + 'a);
+            crate::dyn_pointee!(impl<'a, $($gen),+> dyn $($bounds)+ + Send + Sync + 'a);
+        };
+        ($($bounds:tt)+) => {
+            crate::dyn_pointee!(impl<'a> dyn $($bounds)+ + 'a);
+            crate::dyn_pointee!(impl<'a> dyn $($bounds)+ + Send + 'a);
+            crate::dyn_pointee!(impl<'a> dyn $($bounds)+ + Send + Sync + 'a);
+        };
+    }
+
+    macro_rules! fn_dyn_pointees {
+        ($($arg:ident)*) => {
+            core_dyn_pointees!(impl<R $(, $arg)*> Fn($($arg),*) -> R);
+            core_dyn_pointees!(impl<R $(, $arg)*> FnMut($($arg),*) -> R);
+            core_dyn_pointees!(impl<R $(, $arg)*> FnOnce($($arg),*) -> R);
+        };
+    }
+
+    // `Any` is always `'static`.
+    crate::dyn_pointee!(dyn core::any::Any);
+    crate::dyn_pointee!(dyn core::any::Any + Send);
+    crate::dyn_pointee!(dyn core::any::Any + Send + Sync);
+
+    core_dyn_pointees!(fmt::Debug);
+    core_dyn_pointees!(fmt::Display);
+    core_dyn_pointees!(fmt::Write);
+    core_dyn_pointees!(core::error::Error);
+    core_dyn_pointees!(Hasher);
+    core_dyn_pointees!(impl<I> Iterator<Item = I>);
+    core_dyn_pointees!(impl<I> DoubleEndedIterator<Item = I>);
+    core_dyn_pointees!(impl<I> ExactSizeIterator<Item = I>);
+    core_dyn_pointees!(impl<O> core::future::Future<Output = O>);
+
+    fn_dyn_pointees!();
+    fn_dyn_pointees!(A);
+    fn_dyn_pointees!(A B);
+    fn_dyn_pointees!(A B C);
+    fn_dyn_pointees!(A B C D);
+    fn_dyn_pointees!(A B C D E);
+    fn_dyn_pointees!(A B C D E F);
+    fn_dyn_pointees!(A B C D E F G);
+    fn_dyn_pointees!(A B C D E F G H);
+    fn_dyn_pointees!(A B C D E F G H I);
+    fn_dyn_pointees!(A B C D E F G H I J);
+    fn_dyn_pointees!(A B C D E F G H I J K);
+    fn_dyn_pointees!(A B C D E F G H I J K L);
+
+    /// # `DynMetadata`
+    ///
+    /// A stand-in for `core::ptr::DynMetadata`, the metadata of a trait object, which is a pointer
+    /// to its vtable.
+    pub struct DynMetadata<Dyn: ?Sized> {
+        vtable: NonNull<()>,
+        _phantom: PhantomData<*const Dyn>,
+    }
+
+    // SAFETY: It's a pointer to a vtable, which is immutable and lives forever.
+    unsafe impl<Dyn: ?Sized> Send for DynMetadata<Dyn> {}
+    // SAFETY: See above.
+    unsafe impl<Dyn: ?Sized> Sync for DynMetadata<Dyn> {}
+
+    impl<Dyn: ?Sized> DynMetadata<Dyn> {
+        /// Takes the metadata out of a trait object pointer.
+        ///
+        /// # Safety
+        /// `Dyn` has to be a trait object.
+        #[inline]
+        unsafe fn new(ptr: *const Dyn) -> Self {
+            // SAFETY: The caller promised this is a trait object.
+            let [_, vtable] = unsafe { Repr::split(ptr) };
+            Self {
+                vtable: NonNull::new(vtable.cast_mut()).expect("trait object vtables aren't null"),
+                _phantom: PhantomData,
+            }
+        }
+
+        /// Builds a trait object pointer at `data` with this vtable.
+        ///
+        /// # Safety
+        /// `Dyn` has to be a trait object.
+        #[inline]
+        unsafe fn with_data(self, data: *const ()) -> *const Dyn {
+            // SAFETY: The caller promised this is a trait object, and the vtable came out of one.
+            unsafe { Repr::join(data, self.vtable.as_ptr().cast_const()) }
+        }
+
+        /// Returns the address of the vtable.
+        #[inline]
+        pub(crate) fn vtable_addr(self) -> usize {
+            self.vtable.as_ptr().addr()
+        }
+    }
+
+    impl<Dyn: ?Sized> fmt::Debug for DynMetadata<Dyn> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "DynMetadata({:#x})", self.vtable_addr())
+        }
+    }
+
+    impl<Dyn: ?Sized> Clone for DynMetadata<Dyn> {
+        #[inline]
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<Dyn: ?Sized> Copy for DynMetadata<Dyn> {}
+
+    impl<Dyn: ?Sized> PartialEq for DynMetadata<Dyn> {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.vtable_addr() == other.vtable_addr()
+        }
+    }
+
+    impl<Dyn: ?Sized> Eq for DynMetadata<Dyn> {}
+
+    impl<Dyn: ?Sized> PartialOrd for DynMetadata<Dyn> {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<Dyn: ?Sized> Ord for DynMetadata<Dyn> {
+        #[inline]
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.vtable_addr().cmp(&other.vtable_addr())
+        }
+    }
+
+    impl<Dyn: ?Sized> Hash for DynMetadata<Dyn> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.vtable_addr().hash(state);
+        }
+    }
+}
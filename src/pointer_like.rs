@@ -0,0 +1,359 @@
+use core::ptr::NonNull;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::formatting::FmtMetadata;
+use crate::{pointee, NullOr, OnlyNull, OnlyNullMut, Pointee};
+
+/// What is known about whether a [`PointerLike`] is null, just from its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnownNullability {
+    /// The pointer is always null.
+    Null,
+    /// The pointer is never null.
+    NonNull,
+    /// The pointer could be either, you have to look at it.
+    Unknown,
+}
+
+/// # `PointerLike`
+///
+/// Anything that is, at the end of the day, just an address and some metadata. This lets you write
+/// code once for raw pointers, [`NonNull`], [`OnlyNull`], references and so on, and still get to
+/// skip the null check when the type already knows the answer.
+pub trait PointerLike: Sized {
+    /// The type that is pointed to.
+    type Pointee: ?Sized + Pointee<Metadata = Self::Metadata>;
+
+    /// The metadata of the pointer, same as `<Self::Pointee as Pointee>::Metadata`.
+    type Metadata: Copy + FmtMetadata;
+
+    /// The same kind of pointer, but pointing to `U` instead. For references this is a raw pointer,
+    /// since the lifetime can't be carried over to any `U`.
+    type Cast<U>: PointerLike<Pointee = U, Metadata = Self::Metadata>
+    where
+        U: ?Sized + Pointee<Metadata = Self::Metadata>;
+
+    /// Whether the pointer is null, if that can be known without looking at it.
+    const KNOWN_NULLABILITY: KnownNullability;
+
+    /// Returns a raw pointer to the pointee, without giving up ownership.
+    fn as_ptr(&self) -> *const Self::Pointee;
+
+    /// Splits the pointer into its address and metadata.
+    ///
+    /// For owning pointers this gives up ownership, the same way `Box::into_raw` does.
+    fn to_raw_parts(self) -> (*const (), Self::Metadata);
+
+    /// Creates the pointer from an address and metadata.
+    ///
+    /// # Safety
+    /// The parts have to make a valid value of `Self`. For example, a [`NonNull`] needs a non-null
+    /// address, a reference needs to point to a valid value, and an [`OnlyNull`] needs a null one.
+    unsafe fn from_raw_parts(data: *const (), meta: Self::Metadata) -> Self;
+
+    /// Returns true if the pointer is null. Doesn't even look at the pointer if it doesn't have to.
+    #[inline]
+    fn is_null(&self) -> bool {
+        match Self::KNOWN_NULLABILITY {
+            KnownNullability::Null => true,
+            KnownNullability::NonNull => false,
+            KnownNullability::Unknown => self.as_ptr().is_null(),
+        }
+    }
+
+    /// Returns the metadata of the pointer.
+    #[inline]
+    fn metadata(&self) -> Self::Metadata {
+        pointee::metadata(self.as_ptr())
+    }
+
+    /// Converts into a raw pointer, giving up ownership if there was any.
+    #[inline]
+    fn into_raw(self) -> *const Self::Pointee {
+        let (data, meta) = self.to_raw_parts();
+        pointee::from_raw_parts(data, meta)
+    }
+
+    /// Casts to the same kind of pointer to another type.
+    ///
+    /// # Safety
+    /// The result has to be a valid value of `Self::Cast<U>`. For raw pointers, [`NonNull`] and
+    /// [`OnlyNull`] that is always the case.
+    #[inline]
+    #[must_use]
+    unsafe fn cast<U>(self) -> Self::Cast<U>
+    where
+        U: ?Sized + Pointee<Metadata = Self::Metadata>,
+    {
+        let (data, meta) = self.to_raw_parts();
+        unsafe { <Self::Cast<U> as PointerLike>::from_raw_parts(data, meta) }
+    }
+}
+
+impl<T> PointerLike for *const T
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = *const U
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::Unknown;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        *self
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        (self.cast(), pointee::metadata(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        pointee::from_raw_parts(data, meta)
+    }
+}
+
+impl<T> PointerLike for *mut T
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = *mut U
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::Unknown;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.cast_const()
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        (self.cast_const().cast(), pointee::metadata(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        pointee::from_raw_parts_mut(data.cast_mut(), meta)
+    }
+}
+
+impl<T> PointerLike for NonNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = NonNull<U>
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::NonNull;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        NonNull::as_ptr(*self).cast_const()
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        PointerLike::to_raw_parts(NonNull::as_ptr(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        unsafe { NonNull::new_unchecked(pointee::from_raw_parts_mut(data.cast_mut(), meta)) }
+    }
+}
+
+impl<T> PointerLike for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = OnlyNull<U>
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::Null;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        OnlyNull::as_ptr(*self)
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        OnlyNull::to_raw_parts(self)
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        debug_assert!(data.is_null());
+        Self::from_metadata(meta)
+    }
+}
+
+impl<T> PointerLike for OnlyNullMut<T>
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = OnlyNullMut<U>
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::Null;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.cast_const().as_ptr()
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        self.cast_const().to_raw_parts()
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        debug_assert!(data.is_null());
+        Self::from_metadata(meta)
+    }
+}
+
+impl<T> PointerLike for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = NullOr<U>
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::Unknown;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        (*self).into()
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        PointerLike::to_raw_parts(<*const T>::from(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        NullOr::from(pointee::from_raw_parts::<T>(data, meta))
+    }
+}
+
+impl<T> PointerLike for &T
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    // `U` isn't known to outlive `'a`, so casting gives a raw pointer instead.
+    type Cast<U>
+        = *const U
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::NonNull;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        *self
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        PointerLike::to_raw_parts(core::ptr::from_ref(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        unsafe { &*pointee::from_raw_parts(data, meta) }
+    }
+}
+
+impl<T> PointerLike for &mut T
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    // See the implementation for `&T`.
+    type Cast<U>
+        = *mut U
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::NonNull;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        core::ptr::from_ref(&**self)
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        PointerLike::to_raw_parts(core::ptr::from_mut(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        unsafe { &mut *pointee::from_raw_parts_mut(data.cast_mut(), meta) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PointerLike for Box<T>
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+    type Metadata = T::Metadata;
+    type Cast<U>
+        = Box<U>
+    where
+        U: ?Sized + Pointee<Metadata = T::Metadata>;
+
+    const KNOWN_NULLABILITY: KnownNullability = KnownNullability::NonNull;
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        core::ptr::from_ref(&**self)
+    }
+
+    #[inline]
+    fn to_raw_parts(self) -> (*const (), T::Metadata) {
+        PointerLike::to_raw_parts(Box::into_raw(self))
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts(data: *const (), meta: T::Metadata) -> Self {
+        unsafe { Box::from_raw(pointee::from_raw_parts_mut(data.cast_mut(), meta)) }
+    }
+}
@@ -0,0 +1,379 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ptr::NonNull;
+
+use crate::{pointee, ConvertToOnlyNullError, NullOr, OnlyNull, Pointee};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// What is known about whether a [`Ptr`] is null. This is implemented by [`Null`],
+/// [`NonNullMarker`] and [`Unknown`], and nothing else.
+pub trait Nullability: sealed::Sealed {
+    /// How a pointer with this nullability is stored.
+    type Repr<T>: Copy + fmt::Debug
+    where
+        T: ?Sized + Pointee;
+
+    /// Converts the stored pointer to a raw pointer.
+    fn as_ptr<T>(repr: Self::Repr<T>) -> *const T
+    where
+        T: ?Sized + Pointee;
+}
+
+/// The pointer is null, and is stored as an [`OnlyNull`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Null {}
+
+/// The pointer is not null, and is stored as a [`NonNull`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NonNullMarker {}
+
+/// Nobody knows, and the pointer is stored as a raw pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unknown {}
+
+impl sealed::Sealed for Null {}
+impl sealed::Sealed for NonNullMarker {}
+impl sealed::Sealed for Unknown {}
+
+impl Nullability for Null {
+    type Repr<T>
+        = OnlyNull<T>
+    where
+        T: ?Sized + Pointee;
+
+    #[inline]
+    fn as_ptr<T>(repr: OnlyNull<T>) -> *const T
+    where
+        T: ?Sized + Pointee,
+    {
+        repr.as_ptr()
+    }
+}
+
+impl Nullability for NonNullMarker {
+    type Repr<T>
+        = NonNull<T>
+    where
+        T: ?Sized + Pointee;
+
+    #[inline]
+    fn as_ptr<T>(repr: NonNull<T>) -> *const T
+    where
+        T: ?Sized + Pointee,
+    {
+        repr.as_ptr().cast_const()
+    }
+}
+
+impl Nullability for Unknown {
+    type Repr<T>
+        = *const T
+    where
+        T: ?Sized + Pointee;
+
+    #[inline]
+    fn as_ptr<T>(repr: *const T) -> *const T
+    where
+        T: ?Sized + Pointee,
+    {
+        repr
+    }
+}
+
+/// # `Ptr`
+///
+/// A pointer that keeps track of whether it's null in its type. A `Ptr<T, Null>` is an
+/// [`OnlyNull<T>`] with extra steps, a `Ptr<T, NonNullMarker>` is a [`NonNull<T>`], and a
+/// `Ptr<T, Unknown>` is a raw pointer that you have to [`Ptr::classify_state`] before you can do
+/// anything interesting with it.
+///
+/// `Ptr<T, Null>` wraps an [`OnlyNull<T>`] instead of being the same type, so that [`OnlyNull`]
+/// keeps its own name, formatting and trait impls. The two convert into each other for free.
+pub struct Ptr<T, N>
+where
+    T: ?Sized + Pointee,
+    N: Nullability,
+{
+    repr: N::Repr<T>,
+}
+
+/// The result of [`Ptr::classify_state`], a [`Ptr`] whose nullability is known.
+///
+/// This is [`NullOr`] with each side wrapped in its [`Ptr`], and converts to and from one.
+pub enum PtrState<T>
+where
+    T: ?Sized + Pointee,
+{
+    /// The pointer was null.
+    Null(Ptr<T, Null>),
+    /// The pointer was not null.
+    NonNull(Ptr<T, NonNullMarker>),
+}
+
+impl<T, N> Ptr<T, N>
+where
+    T: ?Sized + Pointee,
+    N: Nullability,
+{
+    /// Converts to a raw pointer.
+    #[inline]
+    #[must_use]
+    pub fn as_ptr(self) -> *const T {
+        N::as_ptr(self.repr)
+    }
+
+    /// Returns the metadata of the pointer.
+    #[inline]
+    #[must_use]
+    pub fn metadata(self) -> T::Metadata {
+        pointee::metadata(self.as_ptr())
+    }
+
+    /// Forgets what is known about the nullability of the pointer.
+    #[inline]
+    #[must_use]
+    pub fn forget(self) -> Ptr<T, Unknown> {
+        Ptr::new(self.as_ptr())
+    }
+}
+
+impl<T> Ptr<T, Unknown>
+where
+    T: ?Sized + Pointee,
+{
+    /// Creates a pointer that may or may not be null.
+    #[inline]
+    #[must_use]
+    pub const fn new(ptr: *const T) -> Self {
+        Self { repr: ptr }
+    }
+
+    /// Finds out whether the pointer is null, and puts that in the type.
+    ///
+    /// This is the same [`NullOr`] that [`PtrExt::classify`](crate::PtrExt::classify) returns, and
+    /// each side converts into the matching `Ptr` with [`Into`].
+    #[inline]
+    #[must_use]
+    pub fn classify(self) -> NullOr<T> {
+        NullOr::from(self.repr)
+    }
+
+    /// Finds out whether the pointer is null, and hands it back as the matching `Ptr`, ready to
+    /// be dereferenced if it isn't null.
+    #[inline]
+    #[must_use]
+    pub fn classify_state(self) -> PtrState<T> {
+        self.classify().into()
+    }
+}
+
+impl<T> Ptr<T, Null>
+where
+    T: ?Sized + Pointee,
+{
+    /// Converts to an [`OnlyNull`].
+    #[inline]
+    #[must_use]
+    pub const fn into_only_null(self) -> OnlyNull<T> {
+        self.repr
+    }
+}
+
+impl<T> Ptr<T, NonNullMarker>
+where
+    T: ?Sized + Pointee,
+{
+    /// Converts to a [`NonNull`].
+    #[inline]
+    #[must_use]
+    pub const fn into_non_null(self) -> NonNull<T> {
+        self.repr
+    }
+
+    /// Returns a shared reference to the value.
+    ///
+    /// # Safety
+    /// The same rules as [`NonNull::as_ref`] apply.
+    #[inline]
+    #[must_use]
+    pub unsafe fn as_ref<'a>(self) -> &'a T {
+        unsafe { self.repr.as_ref() }
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// # Safety
+    /// The same rules as [`NonNull::as_mut`] apply.
+    #[inline]
+    #[must_use]
+    pub unsafe fn as_mut<'a>(self) -> &'a mut T {
+        let mut ptr = self.repr;
+        unsafe { ptr.as_mut() }
+    }
+}
+
+impl<T, N> fmt::Debug for Ptr<T, N>
+where
+    T: ?Sized + Pointee,
+    N: Nullability,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ptr").field(&self.repr).finish()
+    }
+}
+
+impl<T, N> Clone for Ptr<T, N>
+where
+    T: ?Sized + Pointee,
+    N: Nullability,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, N> Copy for Ptr<T, N>
+where
+    T: ?Sized + Pointee,
+    N: Nullability,
+{
+}
+
+impl<T> fmt::Debug for PtrState<T>
+where
+    T: ?Sized + Pointee,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null(ptr) => f.debug_tuple("Null").field(ptr).finish(),
+            Self::NonNull(ptr) => f.debug_tuple("NonNull").field(ptr).finish(),
+        }
+    }
+}
+
+impl<T> Clone for PtrState<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PtrState<T> where T: ?Sized + Pointee {}
+
+impl<T> From<NullOr<T>> for PtrState<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: NullOr<T>) -> Self {
+        match ptr {
+            NullOr::Null(ptr) => Self::Null(ptr.into()),
+            NullOr::NonNull(ptr) => Self::NonNull(ptr.into()),
+        }
+    }
+}
+
+impl<T> From<PtrState<T>> for NullOr<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: PtrState<T>) -> Self {
+        match ptr {
+            PtrState::Null(ptr) => Self::Null(ptr.into()),
+            PtrState::NonNull(ptr) => Self::NonNull(ptr.into()),
+        }
+    }
+}
+
+impl<T> From<OnlyNull<T>> for Ptr<T, Null>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: OnlyNull<T>) -> Self {
+        Self { repr: ptr }
+    }
+}
+
+impl<T> From<Ptr<T, Null>> for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: Ptr<T, Null>) -> Self {
+        ptr.into_only_null()
+    }
+}
+
+impl<T> From<NonNull<T>> for Ptr<T, NonNullMarker>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: NonNull<T>) -> Self {
+        Self { repr: ptr }
+    }
+}
+
+impl<T> From<Ptr<T, NonNullMarker>> for NonNull<T>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: Ptr<T, NonNullMarker>) -> Self {
+        ptr.into_non_null()
+    }
+}
+
+impl<T> From<*const T> for Ptr<T, Unknown>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: *const T) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<T> From<*mut T> for Ptr<T, Unknown>
+where
+    T: ?Sized + Pointee,
+{
+    #[inline]
+    fn from(ptr: *mut T) -> Self {
+        Self::new(ptr.cast_const())
+    }
+}
+
+impl<T> TryFrom<Ptr<T, Unknown>> for Ptr<T, Null>
+where
+    T: ?Sized + Pointee,
+{
+    type Error = ConvertToOnlyNullError<*const T>;
+
+    fn try_from(ptr: Ptr<T, Unknown>) -> Result<Self, Self::Error> {
+        OnlyNull::try_from(ptr.repr).map(Self::from)
+    }
+}
+
+impl<T> TryFrom<Ptr<T, Unknown>> for Ptr<T, NonNullMarker>
+where
+    T: ?Sized + Pointee,
+{
+    type Error = Ptr<T, Null>;
+
+    fn try_from(ptr: Ptr<T, Unknown>) -> Result<Self, Self::Error> {
+        match ptr.classify_state() {
+            PtrState::Null(ptr) => Err(ptr),
+            PtrState::NonNull(ptr) => Ok(ptr),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use core::ptr::NonNull;
+
+use crate::{NullOr, OnlyNull, Pointee};
+
+/// # `PtrExt`
+///
+/// Splits raw pointers into the two worlds where you know what you're dealing with: [`OnlyNull`]
+/// and [`NonNull`]. The metadata comes along in both cases.
+pub trait PtrExt {
+    /// The type that is pointed to.
+    type Pointee: ?Sized + Pointee;
+
+    /// Finds out whether the pointer is null.
+    fn classify(self) -> NullOr<Self::Pointee>;
+
+    /// Returns the pointer as a [`NonNull`], or the [`OnlyNull`] it turned out to be. This works
+    /// with `?` if you want to bail out on null.
+    ///
+    /// # Errors
+    /// Fails if the pointer is null.
+    fn non_null(self) -> Result<NonNull<Self::Pointee>, OnlyNull<Self::Pointee>>;
+
+    /// Returns the pointer as an [`OnlyNull`], or the [`NonNull`] it turned out to be.
+    ///
+    /// # Errors
+    /// Fails if the pointer isn't null.
+    fn only_null(self) -> Result<OnlyNull<Self::Pointee>, NonNull<Self::Pointee>>;
+}
+
+impl<T> PtrExt for *mut T
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+
+    #[inline]
+    fn classify(self) -> NullOr<T> {
+        NullOr::from(self)
+    }
+
+    #[inline]
+    fn non_null(self) -> Result<NonNull<T>, OnlyNull<T>> {
+        match self.classify() {
+            NullOr::Null(ptr) => Err(ptr),
+            NullOr::NonNull(ptr) => Ok(ptr),
+        }
+    }
+
+    #[inline]
+    fn only_null(self) -> Result<OnlyNull<T>, NonNull<T>> {
+        match self.classify() {
+            NullOr::Null(ptr) => Ok(ptr),
+            NullOr::NonNull(ptr) => Err(ptr),
+        }
+    }
+}
+
+impl<T> PtrExt for *const T
+where
+    T: ?Sized + Pointee,
+{
+    type Pointee = T;
+
+    #[inline]
+    fn classify(self) -> NullOr<T> {
+        self.cast_mut().classify()
+    }
+
+    #[inline]
+    fn non_null(self) -> Result<NonNull<T>, OnlyNull<T>> {
+        self.cast_mut().non_null()
+    }
+
+    #[inline]
+    fn only_null(self) -> Result<OnlyNull<T>, NonNull<T>> {
+        self.cast_mut().only_null()
+    }
+}
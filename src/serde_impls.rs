@@ -0,0 +1,104 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{OnlyNull, Pointee};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for () {}
+    impl Sealed for usize {}
+}
+
+/// Metadata that can be serialized. Only no metadata at all and lengths are supported, a vtable
+/// doesn't mean anything outside of the program that it came from.
+pub trait SerdeMetadata: Sized + sealed::Sealed {
+    /// Serializes the metadata.
+    ///
+    /// # Errors
+    /// Fails if the serializer fails.
+    fn serialize<S: Serializer>(self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Deserializes the metadata.
+    ///
+    /// # Errors
+    /// Fails if the deserializer fails, or if it finds something that isn't a null pointer.
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl SerdeMetadata for () {
+    fn serialize<S: Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Formats like JSON reject anything but a unit in `deserialize_unit` before the visitor
+        // gets to say which pointer it found, so those get to describe what they read instead.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NullVisitor)
+        } else {
+            deserializer.deserialize_unit(NullVisitor)
+        }
+    }
+}
+
+impl SerdeMetadata for usize {
+    fn serialize<S: Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self as u64)
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let len = u64::deserialize(deserializer)?;
+        usize::try_from(len).map_err(|_| de::Error::custom("length doesn't fit in a usize"))
+    }
+}
+
+struct NullVisitor;
+
+impl Visitor<'_> for NullVisitor {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a null pointer")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    // The smaller integers are forwarded here by serde.
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<(), E> {
+        Err(E::custom(format_args!("pointer {v:#x} is not null")))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<(), E> {
+        Err(E::custom(format_args!("pointer {v:#x} is not null")))
+    }
+}
+
+impl<T> Serialize for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+    T::Metadata: SerdeMetadata,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerdeMetadata::serialize(self.metadata(), serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for OnlyNull<T>
+where
+    T: ?Sized + Pointee,
+    T::Metadata: SerdeMetadata,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <T::Metadata as SerdeMetadata>::deserialize(deserializer).map(Self::from_metadata)
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+
+use packed::{FailAfter, NullAllocator};
+
+#[test]
+fn null_allocator_never_allocates() {
+    let layout = Layout::new::<u64>();
+
+    assert!(unsafe { NullAllocator.alloc(layout) }.is_null());
+    assert!(unsafe { NullAllocator.alloc_zeroed(layout) }.is_null());
+}
+
+#[test]
+fn fail_after_budget() {
+    let allocator = FailAfter::<2, System>::new(System);
+    let layout = Layout::new::<u64>();
+
+    let first = unsafe { allocator.alloc(layout) };
+    let second = unsafe { allocator.alloc_zeroed(layout) };
+    assert!(!first.is_null());
+    assert!(!second.is_null());
+    assert_eq!(allocator.remaining(), 0);
+    assert!(unsafe { allocator.alloc(layout) }.is_null());
+
+    unsafe {
+        allocator.dealloc(first, layout);
+        allocator.dealloc(second, layout);
+    }
+
+    allocator.reset();
+    assert_eq!(allocator.remaining(), 2);
+}
+
+#[cfg(feature = "allocator_api")]
+#[test]
+fn allocator_api() {
+    use std::alloc::Global;
+
+    assert!(Box::try_new_in(1_u8, NullAllocator).is_err());
+
+    let allocator = FailAfter::<1, Global>::new(Global);
+    let first = Vec::<u8, _>::with_capacity_in(4, &allocator);
+    let mut second = Vec::<u8, _>::new_in(&allocator);
+    assert!(second.try_reserve(4).is_err());
+    drop(first);
+}
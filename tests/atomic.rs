@@ -0,0 +1,31 @@
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use packed::{AtomicPtrExt, OnlyNull};
+
+#[test]
+fn empty_slot_is_typed() {
+    let mut value = 3_u32;
+    let value = NonNull::from(&mut value);
+    let slot = AtomicPtr::new(std::ptr::null_mut());
+
+    assert!(slot.is_null_relaxed());
+    assert_eq!(slot.swap_to_null(Ordering::AcqRel), None);
+
+    assert_eq!(
+        slot.compare_exchange_null(value, Ordering::AcqRel, Ordering::Acquire),
+        Ok(OnlyNull::null())
+    );
+    assert_eq!(
+        slot.compare_exchange_null(value, Ordering::AcqRel, Ordering::Acquire),
+        Err(value)
+    );
+    assert!(!slot.load_classified(Ordering::Acquire).is_null());
+
+    assert_eq!(slot.swap_to_null(Ordering::AcqRel), Some(value));
+    assert!(slot.is_null_relaxed());
+
+    slot.store(value.as_ptr(), Ordering::Release);
+    slot.store_null(OnlyNull::null(), Ordering::Release);
+    assert!(slot.is_null_relaxed());
+}
@@ -0,0 +1,75 @@
+use packed::{CastSliceError, OnlyNull};
+
+#[test]
+fn cast_slice_rescales_the_length() {
+    let bytes = OnlyNull::<[u16]>::null_slice(3).cast_slice::<u8>().unwrap();
+    assert_eq!(bytes.len(), 6);
+
+    let words = bytes.cast_slice::<u16>().unwrap();
+    assert_eq!(words, OnlyNull::null_slice(3));
+    assert_eq!(
+        OnlyNull::<[[u8; 3]]>::null_slice(2).cast_slice::<u16>(),
+        Ok(OnlyNull::null_slice(3))
+    );
+}
+
+#[test]
+fn cast_slice_needs_whole_elements() {
+    assert_eq!(
+        OnlyNull::<[u8; 3]>::null()
+            .unsize_array()
+            .cast_slice::<u16>(),
+        Err(CastSliceError)
+    );
+    assert_eq!(
+        OnlyNull::<[[u8; 3]]>::null_slice(1).cast_slice::<u16>(),
+        Err(CastSliceError)
+    );
+}
+
+#[test]
+fn cast_slice_checks_for_overflow() {
+    assert_eq!(
+        OnlyNull::<[u16]>::null_slice(usize::MAX).cast_slice::<u8>(),
+        Err(CastSliceError)
+    );
+}
+
+#[test]
+fn cast_slice_of_zero_sized_types() {
+    assert_eq!(
+        OnlyNull::<[()]>::null_slice(7).cast_slice::<[u8; 0]>(),
+        Ok(OnlyNull::null_slice(7))
+    );
+    assert_eq!(
+        OnlyNull::<[u8]>::null_slice(7).cast_slice::<()>(),
+        Err(CastSliceError)
+    );
+    assert_eq!(
+        OnlyNull::<[()]>::null_slice(7).cast_slice::<u8>(),
+        Ok(OnlyNull::null_slice(0))
+    );
+}
+
+#[test]
+fn strings_and_bytes() {
+    let string = OnlyNull::<[u8]>::null_slice(5).to_str();
+    assert_eq!(string, OnlyNull::null_str(5));
+    assert_eq!(string.to_bytes(), OnlyNull::null_slice(5));
+}
+
+#[test]
+fn sized_and_unsized() {
+    let element: OnlyNull<u32> = OnlyNull::<[u32]>::null_slice(4).element();
+    assert_eq!(element, OnlyNull::null());
+
+    let sized: OnlyNull<u64> = OnlyNull::<str>::null_str(4).cast_to_sized();
+    assert!(sized.as_ptr().is_null());
+
+    let slice = OnlyNull::<u8>::null().cast_from_sized::<[u16]>(9);
+    assert_eq!(slice.len(), 9);
+    assert_eq!(
+        OnlyNull::<u8>::null().cast_from_sized::<str>(2),
+        OnlyNull::null_str(2)
+    );
+}
@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::ptr::{self, NonNull};
+
+use packed::OnlyNull;
+
+#[test]
+fn equal_to_null_raw_pointers() {
+    let null = OnlyNull::<u32>::null();
+    let (raw, raw_mut) = (ptr::null::<u32>(), ptr::null_mut::<u32>());
+    let mut value = 5_u32;
+
+    assert!(null == raw);
+    assert!(null == raw_mut);
+    assert!(raw == null);
+    assert!(null != &value as *const u32);
+    assert!(&mut value as *mut u32 != null);
+}
+
+#[test]
+fn ordered_like_raw_pointers() {
+    let value = 5_u32;
+    let raw = &value as *const u32;
+
+    assert!(OnlyNull::<u32>::null() < raw);
+    assert!(raw > OnlyNull::<u32>::null());
+    assert_eq!(
+        OnlyNull::<u32>::null().partial_cmp(&ptr::null::<u32>()),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn fat_pointers_compare_metadata() {
+    let null = OnlyNull::<[u8]>::null_slice(3);
+    let short = ptr::slice_from_raw_parts(ptr::null::<u8>(), 2);
+    let same = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u8>(), 3);
+
+    assert!(null == same);
+    assert!(null != short);
+    assert!(null > short);
+    assert!(short < null);
+
+    let data = [1_u8];
+    assert!(null < ptr::slice_from_raw_parts(data.as_ptr(), 0));
+}
+
+#[test]
+fn dyn_pointers_compare_vtables() {
+    let raw = ptr::null::<u64>() as *const dyn Debug;
+    let null = OnlyNull::try_from(raw).unwrap();
+
+    assert!(null == raw);
+    assert!(raw == null);
+}
+
+#[test]
+fn equal_to_none() {
+    let mut value = 5_u32;
+    let some = Some(NonNull::from(&mut value));
+
+    assert!(OnlyNull::<u32>::null() == None::<NonNull<u32>>);
+    assert!(None::<NonNull<[u8]>> == OnlyNull::<[u8]>::null_slice(3));
+    assert!(OnlyNull::<u32>::null() != some);
+    assert!(OnlyNull::<u32>::null() < some);
+    assert!(some > OnlyNull::<u32>::null());
+}
@@ -0,0 +1,54 @@
+#![cfg_attr(feature = "nightly", feature(ptr_metadata))]
+
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use packed::OnlyNull;
+
+#[test]
+fn null_slice_reaches_the_raw_pointer() {
+    let ptr: *const [u32] = OnlyNull::<[u32]>::null_slice(6).into();
+
+    assert!(ptr.is_null());
+    assert_eq!(ptr.len(), 6);
+}
+
+#[test]
+fn null_str_reaches_the_raw_pointer() {
+    let ptr: *const str = OnlyNull::<str>::null_str(11).into();
+
+    assert!(ptr.is_null());
+    assert_eq!((ptr as *const [u8]).len(), 11);
+}
+
+#[test]
+fn from_metadata_reaches_the_raw_pointer() {
+    let ptr: *mut [u8] = OnlyNull::<[u8]>::from_metadata(2).into();
+    assert_eq!(ptr.len(), 2);
+
+    let raw = std::ptr::null::<u16>() as *const dyn Debug;
+    let meta = OnlyNull::try_from(raw).unwrap().metadata();
+    let ptr = OnlyNull::<dyn Debug>::from_metadata(meta).as_ptr();
+    assert!(ptr.is_null());
+    assert_eq!(OnlyNull::try_from(ptr).unwrap().metadata(), meta);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn null_dyn_reaches_the_raw_pointer() {
+    let ptr = OnlyNull::<dyn Debug>::null_dyn::<[u8; 5]>().as_ptr();
+
+    assert!(ptr.is_null());
+    assert_eq!(std::ptr::metadata(ptr).size_of(), 5);
+}
+
+#[test]
+fn null_dyn_with_reaches_the_raw_pointer() {
+    let ptr = OnlyNull::<dyn Debug>::null_dyn_with(|ptr: *const [u8; 5]| ptr).as_ptr();
+
+    assert!(ptr.is_null());
+    assert_eq!(
+        OnlyNull::try_from(ptr).unwrap(),
+        OnlyNull::<dyn Debug>::from_metadata(OnlyNull::try_from(ptr).unwrap().metadata())
+    );
+}
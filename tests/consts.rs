@@ -0,0 +1,46 @@
+#![cfg_attr(feature = "const_trait_impl", feature(const_trait_impl, const_convert))]
+
+use packed::OnlyNull;
+
+static SENTINELS: [OnlyNull<u32>; 4] = [OnlyNull::NULL; 4];
+
+const EMPTY: OnlyNull<[u8]> = OnlyNull::null_slice(0);
+const BYTES: OnlyNull<[u8]> = match OnlyNull::<[u16]>::null_slice(4).cast_slice::<u8>() {
+    Ok(ptr) => ptr,
+    Err(_) => panic!("words don't fit in bytes"),
+};
+
+#[test]
+fn statics_of_null_pointers() {
+    assert!(SENTINELS.iter().all(|ptr| ptr.is_null()));
+    assert!(EMPTY.is_empty());
+    assert_eq!(BYTES.len(), 8);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn raw_pointers_in_constants() {
+    const RAW: *const [u8] = OnlyNull::<[u8]>::null_slice(3).as_ptr();
+    const BACK: OnlyNull<[u8]> = match OnlyNull::from_ptr(RAW) {
+        Ok(ptr) => ptr,
+        Err(_) => panic!("null pointer wasn't null"),
+    };
+
+    assert_eq!(RAW.len(), 3);
+    assert_eq!(BACK.len(), 3);
+}
+
+#[cfg(feature = "const_trait_impl")]
+#[test]
+fn conversions_in_constants() {
+    use std::convert::TryFrom;
+
+    const RAW: *const u8 = OnlyNull::<u8>::NULL.into();
+    const CONVERTED: OnlyNull<u8> = match OnlyNull::try_from(RAW) {
+        Ok(ptr) => ptr,
+        Err(_) => panic!("null pointer wasn't null"),
+    };
+
+    assert!(RAW.is_null());
+    assert_eq!(CONVERTED, OnlyNull::null());
+}
@@ -0,0 +1,58 @@
+use std::convert::TryFrom;
+use std::fmt::{self, Debug};
+
+use packed::OnlyNull;
+
+trait Plugin<T> {
+    fn run(&self) -> T;
+}
+
+impl Plugin<u8> for u8 {
+    fn run(&self) -> u8 {
+        *self
+    }
+}
+
+packed::dyn_pointee!(impl<'a, T> dyn Plugin<T> + 'a);
+
+#[test]
+fn closures_are_registered() {
+    let null = OnlyNull::<dyn Fn(u8) -> u8>::null_dyn_with(|ptr: *const fn(u8) -> u8| ptr);
+    assert!(null.as_ptr().is_null());
+
+    let raw = std::ptr::null::<fn(u8, u8) -> u8>() as *const (dyn FnMut(u8, u8) -> u8 + Send);
+    assert!(OnlyNull::try_from(raw).is_ok());
+}
+
+#[test]
+fn borrowing_trait_objects_are_registered() {
+    fn check<'a>(iter: &'a mut (dyn Iterator<Item = &'a u8> + 'a)) -> bool {
+        OnlyNull::try_from(iter as *mut (dyn Iterator<Item = &'a u8> + 'a)).is_err()
+    }
+
+    let data = [1_u8, 2];
+    assert!(check(&mut data.iter()));
+
+    let mut out = String::new();
+    let write: *mut (dyn fmt::Write + Send) = &mut out;
+    assert!(OnlyNull::try_from(write).is_err());
+}
+
+#[test]
+fn generic_registration() {
+    let null = OnlyNull::<dyn Plugin<u8>>::null_dyn_with(|ptr: *const u8| ptr);
+    let value = 4_u8;
+    let real: *const dyn Plugin<u8> = &value;
+
+    assert!(null.as_ptr().is_null());
+    assert!(OnlyNull::try_from(real).is_err());
+    assert_eq!(unsafe { &*real }.run(), 4);
+}
+
+#[test]
+fn debug_trait_objects_with_auto_traits() {
+    let null = OnlyNull::<dyn Debug + Send + Sync>::null_dyn_with(|ptr: *const u8| ptr);
+    assert!(format!("{null:?}").starts_with(
+        "OnlyNull<dyn core::fmt::Debug + core::marker::Send + core::marker::Sync>(0x0, vtable="
+    ));
+}
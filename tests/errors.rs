@@ -0,0 +1,40 @@
+use std::convert::TryFrom;
+use std::error::Error;
+
+use packed::OnlyNull;
+
+#[test]
+fn rejected_mut_pointer_comes_back_intact() {
+    let mut value = [1_u8, 2, 3];
+    let raw: *mut [u8] = &mut value[..];
+
+    let error = OnlyNull::try_from(raw).unwrap_err();
+    assert_eq!(error.addr(), raw.addr());
+
+    let back = error.into_inner();
+    assert!(std::ptr::eq(back, raw));
+    unsafe { (*back)[1] = 7 };
+    assert_eq!(value, [1, 7, 3]);
+}
+
+#[test]
+fn display_prints_the_address() {
+    let mut value = 5_u32;
+    let raw: *mut u32 = &mut value;
+    let error = OnlyNull::try_from(raw).unwrap_err();
+
+    assert_eq!(error.to_string(), format!("pointer {raw:p} is not null"));
+    assert_eq!(
+        error.to_string(),
+        format!("pointer {:#x} is not null", error.addr())
+    );
+}
+
+#[test]
+fn usable_as_an_error() {
+    let value = 5_u32;
+    let error: Box<dyn Error> = Box::new(OnlyNull::try_from(&value as *const u32).unwrap_err());
+
+    assert!(error.to_string().ends_with("is not null"));
+    assert!(error.source().is_none());
+}
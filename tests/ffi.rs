@@ -0,0 +1,66 @@
+use std::convert::TryFrom;
+use std::mem::{align_of, size_of};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use packed::{write_c_declaration, OnlyNull, OnlyNullFfi};
+
+#[test]
+fn same_layout_as_a_raw_pointer() {
+    assert_eq!(size_of::<OnlyNullFfi<u8>>(), size_of::<*const u8>());
+    assert_eq!(align_of::<OnlyNullFfi<u8>>(), align_of::<*const u8>());
+    assert_eq!(size_of::<OnlyNullFfi<[u64; 4]>>(), size_of::<*const u8>());
+    assert_eq!(size_of::<OnlyNull<u8>>(), 0);
+}
+
+#[test]
+fn checks_what_comes_back() {
+    let null = OnlyNullFfi::<u32>::from(OnlyNull::null());
+    assert!(null.as_ptr().is_null());
+    assert_eq!(null.get(), Ok(OnlyNull::null()));
+
+    // This is what C could hand back, with the same layout.
+    let value = 5_u32;
+    let raw: *const u32 = &value;
+    let from_c: OnlyNullFfi<u32> = unsafe { std::mem::transmute(raw) };
+
+    let error = from_c.get().unwrap_err();
+    assert_eq!(error.into_inner(), raw);
+    assert!(OnlyNull::try_from(from_c).is_err());
+    assert_ne!(from_c, OnlyNullFfi::null());
+}
+
+#[test]
+fn shareable_and_hashable() {
+    static SHARED: OnlyNullFfi<u8> = OnlyNullFfi::null();
+
+    let hash = |ptr: &OnlyNullFfi<u8>| {
+        let mut hasher = DefaultHasher::new();
+        ptr.hash(&mut hasher);
+        hasher.finish()
+    };
+    let copy = std::thread::spawn(|| SHARED).join().unwrap();
+    assert_eq!(copy, SHARED);
+    assert_eq!(hash(&copy), hash(&OnlyNullFfi::default()));
+}
+
+#[test]
+fn debug_is_the_pointer() {
+    assert_eq!(
+        format!("{:?}", OnlyNullFfi::<String>::null()),
+        "OnlyNullFfi<alloc::string::String>(0x0)"
+    );
+    assert_eq!(format!("{:p}", OnlyNullFfi::<u8>::default()), "0x0");
+}
+
+#[test]
+fn c_declaration() {
+    let mut out = String::new();
+    write_c_declaration(&mut out, "null_callback_data", "struct data").unwrap();
+    assert_eq!(
+        out,
+        "/* A pointer to `struct data` that must always be NULL. */\n\
+         typedef const struct data *null_callback_data;\n"
+    );
+}
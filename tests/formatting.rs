@@ -0,0 +1,54 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use packed::{OnlyAddr, OnlyDangling, OnlyNull};
+
+#[test]
+fn pointer_and_display() {
+    assert_eq!(format!("{:p}", OnlyNull::<u8>::null()), "0x0");
+    assert_eq!(
+        format!("{:p}", OnlyNull::<[u8]>::null_slice(3)),
+        format!(
+            "{:p}",
+            std::ptr::slice_from_raw_parts(std::ptr::null::<u8>(), 3)
+        )
+    );
+    assert_eq!(format!("{}", OnlyNull::<str>::null_str(3)), "0x0");
+    assert_eq!(
+        format!("{:p}", OnlyNull::<u8>::null()),
+        format!("{:p}", std::ptr::null::<u8>())
+    );
+    assert_eq!(format!("{:p}", OnlyDangling::<u32>::dangling()), "0x4");
+}
+
+#[test]
+fn debug_includes_metadata() {
+    assert_eq!(format!("{:?}", OnlyNull::<u8>::null()), "OnlyNull<u8>(0x0)");
+    assert_eq!(
+        format!("{:?}", OnlyNull::<[u8]>::null_slice(12)),
+        "OnlyNull<[u8]>(0x0, len=12)"
+    );
+    assert_eq!(
+        format!("{:?}", OnlyAddr::<str, 0x10>::from_metadata(2)),
+        "OnlyAddr<str>(0x10, len=2)"
+    );
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn debug_includes_vtable_layout() {
+    let debug = format!("{:?}", OnlyNull::<dyn Debug>::null_dyn::<u64>());
+    assert!(debug.starts_with("OnlyNull<dyn core::fmt::Debug>(0x0, vtable=0x"));
+    assert!(debug.ends_with(", size=8, align=8)"));
+
+    let vtable = &debug["OnlyNull<dyn core::fmt::Debug>(0x0, vtable=0x".len()..];
+    let vtable = &vtable[..vtable.find(',').unwrap()];
+    assert!(usize::from_str_radix(vtable, 16).unwrap() != 0);
+}
+
+#[test]
+fn debug_includes_vtable() {
+    let null = std::ptr::null::<u64>() as *const dyn Debug;
+    let debug = format!("{:?}", OnlyNull::try_from(null).unwrap());
+    assert!(debug.starts_with("OnlyNull<dyn core::fmt::Debug>(0x0, vtable=0x"));
+}
@@ -0,0 +1,71 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use packed::OnlyNull;
+
+#[test]
+fn metadata_is_what_the_pointer_was_built_with() {
+    assert_eq!(OnlyNull::<[u8]>::null_slice(4).metadata(), 4);
+    assert_eq!(OnlyNull::<str>::null_str(0).metadata(), 0);
+    assert_eq!(OnlyNull::<u8>::null().metadata(), ());
+
+    let raw = std::ptr::slice_from_raw_parts(std::ptr::null::<u8>(), 8);
+    assert_eq!(OnlyNull::try_from(raw).unwrap().metadata(), 8);
+}
+
+#[test]
+fn with_metadata_replaces_it() {
+    let null = OnlyNull::<[u8]>::null_slice(4).with_metadata(10);
+    assert_eq!(null.len(), 10);
+
+    let ptr: *const [u8] = null.into();
+    assert_eq!(ptr.len(), 10);
+}
+
+#[test]
+fn map_metadata_changes_the_type() {
+    let bytes: OnlyNull<[u8]> = OnlyNull::<str>::null_str(6).map_metadata(|len| len);
+    assert_eq!(bytes.len(), 6);
+
+    let halved: OnlyNull<[u16]> = bytes.map_metadata(|len| len / 2);
+    assert_eq!(halved.len(), 3);
+
+    let sized: OnlyNull<u32> = halved.map_metadata(|_| ());
+    assert!(sized.as_ptr().is_null());
+}
+
+#[test]
+fn slice_length() {
+    let null = OnlyNull::<[u64]>::null_slice(3);
+    assert_eq!(null.len(), 3);
+    assert!(!null.is_empty());
+    assert!(OnlyNull::<[u64]>::null_slice(0).is_empty());
+}
+
+#[test]
+fn str_length() {
+    let null = OnlyNull::<str>::null_str(5);
+    assert_eq!(null.len(), 5);
+    assert!(!null.is_empty());
+    assert!(OnlyNull::<str>::null_str(0).is_empty());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn hints_come_from_the_vtable() {
+    let raw = std::ptr::null::<[u32; 3]>() as *const dyn Debug;
+    let null = OnlyNull::try_from(raw).unwrap();
+
+    assert_eq!(null.size_of_val_hint(), 12);
+    assert_eq!(null.align_of_val_hint(), 4);
+}
+
+#[test]
+fn dyn_metadata_survives_with_metadata() {
+    let raw = std::ptr::null::<u8>() as *const dyn Debug;
+    let meta = OnlyNull::try_from(raw).unwrap().metadata();
+    let other = std::ptr::null::<u16>() as *const dyn Debug;
+
+    let null = OnlyNull::try_from(other).unwrap().with_metadata(meta);
+    assert_eq!(null.metadata(), meta);
+}
@@ -0,0 +1,46 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::mem::size_of;
+use std::ptr::NonNull;
+
+use packed::{NullOr, OnlyNull};
+
+#[test]
+fn same_size_as_raw_pointers() {
+    assert_eq!(size_of::<NullOr<u8>>(), size_of::<*const u8>());
+    assert_eq!(size_of::<NullOr<[u8]>>(), size_of::<*const [u8]>());
+    assert_eq!(
+        size_of::<NullOr<dyn Debug>>(),
+        size_of::<*const dyn Debug>()
+    );
+}
+
+#[test]
+fn option_non_null_round_trip() {
+    let mut value = 5_u32;
+    let non_null = NonNull::from(&mut value);
+    let some = Some(non_null);
+
+    let none: Option<NonNull<u32>> = OnlyNull::<u32>::null().into();
+    assert_eq!(none, None);
+    assert_eq!(OnlyNull::try_from(none), Ok(OnlyNull::null()));
+    assert_eq!(OnlyNull::try_from(some).unwrap_err().into_inner(), non_null);
+
+    let split = NullOr::from(non_null);
+    assert!(!split.is_null());
+    assert_eq!(Option::<NonNull<u32>>::from(split), some);
+
+    let split = NullOr::from(OnlyNull::<u32>::null());
+    assert!(split.is_null());
+    assert_eq!(Option::<NonNull<u32>>::from(split), None);
+}
+
+#[test]
+fn raw_pointers_keep_metadata() {
+    let null = std::ptr::slice_from_raw_parts(std::ptr::null::<u8>(), 4);
+    let split = NullOr::from(null);
+
+    assert_eq!(split.null(), Some(OnlyNull::null_slice(4)));
+    assert_eq!(split.metadata(), 4);
+    assert_eq!(<*const [u8]>::from(split), null);
+}
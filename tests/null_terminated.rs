@@ -0,0 +1,88 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr::{self, NonNull};
+
+use packed::{MissingTerminatorError, NullTerminated, OnlyNull};
+
+fn argv() -> [*const c_char; 4] {
+    [
+        b"ls\0".as_ptr().cast(),
+        b"-l\0".as_ptr().cast(),
+        ptr::null(),
+        b"ignored\0".as_ptr().cast(),
+    ]
+}
+
+#[test]
+fn walks_to_the_first_null() {
+    let argv = argv();
+    let args = NullTerminated::from_slice(&argv).unwrap();
+
+    assert_eq!(args.len(), 2);
+    assert!(!args.is_empty());
+    assert_eq!(args.as_slice().len(), 2);
+    assert_eq!(args.terminator(), OnlyNull::null());
+
+    let strings: Vec<_> = args
+        .map(|arg| unsafe { CStr::from_ptr(arg.as_ptr()) }.to_str().unwrap())
+        .collect();
+    assert_eq!(strings, ["ls", "-l"]);
+}
+
+#[test]
+fn wraps_raw_arrays() {
+    let argv = argv();
+    let args = unsafe { NullTerminated::from_ptr(NonNull::from(&argv).cast()) };
+
+    assert_eq!(args.as_ptr(), argv.as_ptr());
+    assert_eq!(args.as_slice()[1].as_ptr().cast_const(), argv[1]);
+}
+
+#[test]
+fn needs_a_terminator() {
+    let empty = [ptr::null::<u8>()];
+    let full = [&0_u8 as *const u8];
+
+    assert!(NullTerminated::from_slice(&empty).unwrap().is_empty());
+    assert_eq!(
+        NullTerminated::from_slice(&full).unwrap_err(),
+        MissingTerminatorError
+    );
+}
+
+#[test]
+fn unsized_terminators_keep_metadata() {
+    let data = [1_u8, 2, 3];
+    let slices = [
+        ptr::slice_from_raw_parts(data.as_ptr(), 3),
+        ptr::slice_from_raw_parts(ptr::null(), 7),
+    ];
+    let array = NullTerminated::from_slice(&slices).unwrap();
+
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.terminator().metadata(), 7);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn builder_appends_the_terminator() {
+    use packed::NullTerminatedBuilder;
+
+    let values = [1_u32, 2, 3];
+    let ptrs: Vec<_> = values.iter().map(NonNull::from).collect();
+
+    let mut builder = NullTerminatedBuilder::from_slice(&ptrs[..2]);
+    assert_eq!(builder.as_null_terminated().len(), 2);
+    builder.push(ptrs[2]);
+
+    let array = builder.as_null_terminated();
+    assert_eq!(array.as_slice(), &ptrs[..]);
+    assert_eq!(format!("{:?}", array), format!("{:?}", ptrs));
+
+    let raw = builder.into_vec();
+    assert_eq!(raw.len(), 4);
+    assert!(raw[3].is_null());
+
+    let slices = NullTerminatedBuilder::with_terminator(OnlyNull::<[u32]>::null_slice(2));
+    assert_eq!(slices.as_null_terminated().terminator().metadata(), 2);
+}
@@ -0,0 +1,43 @@
+use std::convert::TryFrom;
+
+use packed::OnlyNullFn;
+
+type Callback = unsafe extern "C" fn(*mut u8, usize) -> i32;
+
+unsafe extern "C" fn callback(_: *mut u8, len: usize) -> i32 {
+    len as i32
+}
+
+#[test]
+fn converts_to_no_callback() {
+    let null = OnlyNullFn::<Callback>::NULL;
+
+    assert!(Option::<Callback>::from(null).is_none());
+    assert!(<*const ()>::from(null).is_null());
+    assert_eq!(
+        format!("{:p}", null),
+        format!("{:p}", std::ptr::null::<()>())
+    );
+}
+
+#[test]
+fn checks_callback_slots() {
+    assert!(OnlyNullFn::<Callback>::from_addr(0).is_ok());
+    assert!(OnlyNullFn::<Callback>::try_from(std::ptr::null::<()>()).is_ok());
+    assert!(OnlyNullFn::<Callback>::try_from(None::<Callback>).is_ok());
+
+    let registered = callback as Callback;
+    let error = OnlyNullFn::try_from(Some(registered)).unwrap_err();
+    assert_eq!(unsafe { error.into_inner()(std::ptr::null_mut(), 4) }, 4);
+
+    let error = OnlyNullFn::<Callback>::from_addr(registered as usize).unwrap_err();
+    assert_eq!(error.addr(), registered as usize);
+}
+
+#[test]
+fn plain_rust_functions() {
+    let null = OnlyNullFn::<fn(u8, u16) -> bool>::null();
+
+    assert!(null.get().is_none());
+    assert_eq!(null.cast::<fn()>(), OnlyNullFn::default());
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "alloc")]
+
+use std::convert::TryFrom;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use packed::OnlyNull;
+
+fn takes_box(value: impl Into<Option<Box<u32>>>) -> Option<Box<u32>> {
+    value.into()
+}
+
+#[test]
+fn only_null_is_none() {
+    assert_eq!(takes_box(OnlyNull::null()), None);
+    assert_eq!(Option::<&u32>::from(OnlyNull::null()), None);
+    assert_eq!(Option::<&mut u32>::from(OnlyNull::null()), None);
+    assert_eq!(Option::<Rc<u32>>::from(OnlyNull::null()), None);
+    assert_eq!(Option::<Arc<u32>>::from(OnlyNull::null()), None);
+}
+
+#[test]
+fn unsized_none_of() {
+    let null = OnlyNull::<str>::null_str(4);
+
+    assert_eq!(null.none_of::<Box<str>>(), None);
+    assert_eq!(null.none_of::<Rc<str>>(), None);
+    assert_eq!(null.none_of::<&str>(), None);
+}
+
+#[test]
+fn none_is_only_null() {
+    assert!(OnlyNull::<u32>::try_from(None::<Box<u32>>).is_ok());
+    assert!(OnlyNull::<u32>::try_from(None::<&u32>).is_ok());
+
+    let error = OnlyNull::try_from(Some(Box::new(5_u32))).unwrap_err();
+    assert_eq!(*error.into_inner(), 5);
+
+    let mut value = 3_u32;
+    assert!(OnlyNull::try_from(Some(&mut value)).is_err());
+}
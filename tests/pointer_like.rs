@@ -0,0 +1,77 @@
+use std::ptr::NonNull;
+
+use packed::{KnownNullability, OnlyNull, PointerLike};
+
+fn len_or_zero<P>(ptr: &P) -> usize
+where
+    P: PointerLike<Metadata = usize>,
+{
+    if ptr.is_null() {
+        0
+    } else {
+        ptr.metadata()
+    }
+}
+
+#[test]
+fn known_nullability() {
+    assert_eq!(
+        <OnlyNull<u8> as PointerLike>::KNOWN_NULLABILITY,
+        KnownNullability::Null
+    );
+    assert_eq!(
+        <NonNull<u8> as PointerLike>::KNOWN_NULLABILITY,
+        KnownNullability::NonNull
+    );
+    assert_eq!(
+        <&u8 as PointerLike>::KNOWN_NULLABILITY,
+        KnownNullability::NonNull
+    );
+    assert_eq!(
+        <*const u8 as PointerLike>::KNOWN_NULLABILITY,
+        KnownNullability::Unknown
+    );
+}
+
+#[test]
+fn generic_over_pointer_kinds() {
+    let array = [1_u32, 2, 3];
+    let slice: &[u32] = &array;
+
+    assert_eq!(len_or_zero(&slice), 3);
+    assert_eq!(len_or_zero(&NonNull::from(slice)), 3);
+    assert_eq!(len_or_zero(&(slice as *const [u32])), 3);
+    assert_eq!(len_or_zero(&OnlyNull::<[u32]>::null_slice(3)), 0);
+    assert_eq!(
+        len_or_zero(&std::ptr::slice_from_raw_parts(std::ptr::null::<u8>(), 3)),
+        0
+    );
+}
+
+#[test]
+fn raw_parts_round_trip() {
+    let null = OnlyNull::<[u16]>::null_slice(7);
+    let (data, meta) = PointerLike::to_raw_parts(null);
+
+    assert!(data.is_null());
+    assert_eq!(meta, 7);
+    assert_eq!(
+        unsafe { <OnlyNull<[u16]>>::from_raw_parts(data, meta) },
+        null
+    );
+
+    let cast: OnlyNull<[u8]> = unsafe { PointerLike::cast(null) };
+    assert_eq!(cast.len(), 7);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxes() {
+    let boxed: Box<[u8]> = vec![1, 2, 3].into_boxed_slice();
+
+    assert_eq!(len_or_zero(&boxed), 3);
+
+    let (data, meta) = boxed.to_raw_parts();
+    let boxed = unsafe { <Box<[u8]>>::from_raw_parts(data, meta) };
+    assert_eq!(&*boxed, &[1, 2, 3]);
+}
@@ -0,0 +1,61 @@
+use std::convert::TryFrom;
+
+use packed::{NonNullMarker, Null, NullOr, OnlyNull, Ptr, PtrState, Unknown};
+
+#[test]
+fn classify_state_refines_the_state() {
+    let value = [1_u8, 2, 3];
+    let slice: &[u8] = &value;
+
+    match Ptr::<[u8], Unknown>::new(slice).classify_state() {
+        PtrState::NonNull(ptr) => {
+            assert_eq!(unsafe { ptr.as_ref() }, &[1, 2, 3]);
+            assert_eq!(ptr.metadata(), 3);
+        }
+        PtrState::Null(_) => panic!("pointer to a slice was null"),
+    }
+
+    let null: *const [u8] = OnlyNull::null_slice(5).into();
+    match Ptr::new(null).classify_state() {
+        PtrState::Null(ptr) => assert_eq!(ptr.into_only_null().len(), 5),
+        PtrState::NonNull(_) => panic!("null pointer was not null"),
+    }
+}
+
+#[test]
+fn conversions_between_states() {
+    let value = 7_u32;
+
+    let unknown = Ptr::<u32, Unknown>::from(&value as *const u32);
+    assert!(Ptr::<u32, Null>::try_from(unknown).is_err());
+    assert!(Ptr::<u32, NonNullMarker>::try_from(unknown).is_ok());
+
+    let null = Ptr::<u32, Null>::from(OnlyNull::null());
+    assert!(null.forget().as_ptr().is_null());
+    assert!(Ptr::<u32, NonNullMarker>::try_from(null.forget()).is_err());
+}
+
+#[test]
+fn classify_agrees_with_ptr_ext() {
+    use packed::PtrExt;
+
+    let value = 7_u32;
+    let raw = &value as *const u32;
+
+    assert_eq!(
+        Ptr::new(raw).classify().non_null(),
+        raw.classify().non_null()
+    );
+    let null: *const u32 = OnlyNull::null().into();
+    assert!(Ptr::new(null).classify().is_null());
+}
+
+#[test]
+fn state_converts_to_and_from_null_or() {
+    let value = 7_u32;
+    let state = Ptr::new(&value as *const u32).classify_state();
+
+    let null_or = NullOr::from(state);
+    assert!(!null_or.is_null());
+    assert!(matches!(PtrState::from(null_or), PtrState::NonNull(_)));
+}
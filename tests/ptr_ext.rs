@@ -0,0 +1,31 @@
+use std::ptr::NonNull;
+
+use packed::{NullOr, OnlyNull, PtrExt};
+
+fn first(ptr: *const [u8]) -> Result<u8, OnlyNull<[u8]>> {
+    let ptr: NonNull<[u8]> = ptr.non_null()?;
+    Ok(unsafe { ptr.as_ref() }[0])
+}
+
+#[test]
+fn non_null_with_question_mark() {
+    let value = [4_u8, 5];
+
+    assert_eq!(first(&value[..]), Ok(4));
+    assert_eq!(first(OnlyNull::null_slice(9).into()).unwrap_err().len(), 9);
+}
+
+#[test]
+fn metadata_survives_both_branches() {
+    let mut value = [1_u16, 2, 3];
+    let ptr: *mut [u16] = &mut value[..];
+
+    assert_eq!(ptr.only_null().unwrap_err().len(), 3);
+    match ptr.classify() {
+        NullOr::NonNull(ptr) => assert_eq!(ptr.len(), 3),
+        NullOr::Null(_) => panic!("pointer to a slice was null"),
+    }
+
+    let null: *mut [u16] = OnlyNull::null_slice(2).into();
+    assert_eq!(null.only_null().unwrap().len(), 2);
+}
@@ -0,0 +1,53 @@
+use packed::{NullOr, OnlyNull};
+
+#[test]
+fn answers_are_known_in_advance() {
+    let null = OnlyNull::<[u8]>::null_slice(3);
+
+    assert!(null.is_null());
+    assert_eq!(null.addr(), 0);
+    assert!(null.is_aligned());
+    assert_eq!(null.to_raw_parts(), (std::ptr::null(), 3));
+}
+
+#[test]
+fn references_are_none() {
+    let null = OnlyNull::<String>::null();
+
+    assert!(null.as_ref().is_none());
+    assert!(null.as_mut().is_none());
+}
+
+#[test]
+fn zero_offsets_keep_the_type() {
+    let null = OnlyNull::<u32>::null();
+
+    let same: OnlyNull<u32> = unsafe { null.offset(0) };
+    assert_eq!(same, null);
+    assert_eq!(unsafe { null.add(0) }, null);
+    assert_eq!(unsafe { null.sub(0) }, null);
+}
+
+#[test]
+fn wrapping_arithmetic_is_checked() {
+    let null = OnlyNull::<u32>::null();
+
+    assert!(matches!(null.wrapping_add(0), NullOr::Null(_)));
+    match null.wrapping_add(2) {
+        NullOr::NonNull(ptr) => assert_eq!(ptr.as_ptr().addr(), 8),
+        NullOr::Null(_) => panic!("offset pointer was null"),
+    }
+    match null.wrapping_sub(1) {
+        NullOr::NonNull(ptr) => assert_eq!(ptr.as_ptr().addr(), 0_usize.wrapping_sub(4)),
+        NullOr::Null(_) => panic!("offset pointer was null"),
+    }
+    assert!(matches!(null.wrapping_offset(-1), NullOr::NonNull(_)));
+}
+
+#[test]
+fn wrapping_arithmetic_on_zero_sized_types_stays_null() {
+    let null = OnlyNull::<()>::null();
+
+    assert!(matches!(null.wrapping_add(5), NullOr::Null(_)));
+    assert!(matches!(null.wrapping_offset(-5), NullOr::Null(_)));
+}
@@ -0,0 +1,49 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::ptr;
+
+use packed::OnlyNull;
+
+#[test]
+fn slices_round_trip() {
+    let raw = ptr::slice_from_raw_parts(ptr::null::<u32>(), 7);
+    let back: *const [u32] = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+    assert_eq!(back.len(), 7);
+
+    let raw = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 7);
+    let back: *mut [u32] = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+    assert_eq!(back.len(), 7);
+}
+
+#[test]
+fn strs_round_trip() {
+    let raw = ptr::slice_from_raw_parts(ptr::null::<u8>(), 4) as *const str;
+    let back: *const str = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+    assert_eq!((back as *const [u8]).len(), 4);
+
+    let raw = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u8>(), 4) as *mut str;
+    let back: *mut str = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+    assert_eq!((back as *mut [u8]).len(), 4);
+}
+
+#[test]
+fn trait_objects_round_trip() {
+    let raw = ptr::null::<u16>() as *const dyn Debug;
+    let back: *const dyn Debug = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+
+    let raw = ptr::null_mut::<u16>() as *mut dyn Debug;
+    let back: *mut dyn Debug = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+}
+
+#[test]
+fn mutability_can_change_on_the_way() {
+    let raw = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u8>(), 9);
+    let back: *const [u8] = OnlyNull::try_from(raw).unwrap().into();
+    assert!(ptr::eq(back, raw));
+}
@@ -0,0 +1,47 @@
+use std::convert::TryFrom;
+use std::ptr::NonNull;
+
+use packed::{OnlyAddr, OnlyDangling, OnlyNull};
+
+#[test]
+fn dangling_is_non_null_at_alignment() {
+    let ptr = OnlyDangling::<u64>::dangling();
+
+    assert_eq!(ptr.addr(), std::mem::align_of::<u64>());
+    assert_eq!(NonNull::from(ptr), NonNull::<u64>::dangling());
+    assert!(OnlyDangling::<u64>::try_from(NonNull::<u64>::dangling()).is_ok());
+
+    let err = OnlyDangling::<u64>::try_from(std::ptr::null::<u64>()).unwrap_err();
+    assert_eq!(err.addr(), 0);
+    assert_eq!(err.expected_addr(), std::mem::align_of::<u64>());
+}
+
+#[test]
+fn fixed_address() {
+    let ptr = OnlyAddr::<[u32], 0x1000>::from_metadata(4);
+    let raw: *const [u32] = ptr.into();
+
+    assert_eq!(raw.addr(), 0x1000);
+    assert_eq!(raw.len(), 4);
+    assert_eq!(OnlyAddr::<[u32], 0x1000>::try_from(raw).unwrap(), ptr);
+    assert!(OnlyAddr::<[u32], 0x2000>::try_from(raw).is_err());
+}
+
+#[test]
+fn zero_address_is_only_null() {
+    let null = OnlyNull::<str>::null_str(3);
+    let addr: OnlyAddr<str, 0> = null.into();
+
+    assert_eq!(addr.metadata(), 3);
+    assert_eq!(OnlyNull::from(addr), null);
+}
+
+#[test]
+fn fixed_address_shares_the_metadata_methods() {
+    let ptr = OnlyAddr::<str, 0x40>::from_metadata(5);
+
+    let bytes: OnlyAddr<[u8], 0x40> = ptr.map_metadata(|len| len);
+    assert_eq!(bytes.with_metadata(2).metadata(), 2);
+    assert_eq!(ptr.cast::<str>(), ptr);
+    assert_eq!(format!("{ptr:?}"), "OnlyAddr<str>(0x40, len=5)");
+}
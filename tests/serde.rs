@@ -0,0 +1,58 @@
+#![cfg(feature = "serde")]
+
+use packed::OnlyNull;
+
+#[test]
+fn postcard_round_trip() {
+    let mut buffer = [0; 16];
+
+    let bytes = postcard::to_slice(&OnlyNull::<u32>::null(), &mut buffer).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(
+        postcard::from_bytes::<OnlyNull<u32>>(bytes).unwrap(),
+        OnlyNull::null()
+    );
+
+    let bytes = postcard::to_slice(&OnlyNull::<[u16]>::null_slice(300), &mut buffer).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<OnlyNull<[u16]>>(bytes)
+            .unwrap()
+            .len(),
+        300
+    );
+
+    let bytes = postcard::to_slice(&OnlyNull::null_str(5), &mut buffer).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<OnlyNull<str>>(bytes).unwrap().len(),
+        5
+    );
+}
+
+#[test]
+fn json_encoding() {
+    assert_eq!(
+        serde_json::to_string(&OnlyNull::<u8>::null()).unwrap(),
+        "null"
+    );
+    assert_eq!(
+        serde_json::to_string(&OnlyNull::<[u8]>::null_slice(12)).unwrap(),
+        "12"
+    );
+    assert_eq!(
+        serde_json::from_str::<OnlyNull<str>>("3").unwrap(),
+        OnlyNull::null_str(3)
+    );
+}
+
+#[test]
+fn rejects_non_null() {
+    let err = serde_json::from_str::<OnlyNull<u8>>("1234").unwrap_err();
+    assert!(err.to_string().contains("pointer 0x4d2 is not null"));
+    let err = serde_json::from_str::<OnlyNull<u8>>("-1").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("pointer 0xffffffffffffffff is not null"));
+    let err = serde_json::from_str::<OnlyNull<u8>>("\"a\"").unwrap_err();
+    assert!(err.to_string().contains("expected a null pointer"));
+    assert!(serde_json::from_str::<OnlyNull<[u8]>>("null").is_err());
+}
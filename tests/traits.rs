@@ -0,0 +1,115 @@
+use std::collections::hash_map::DefaultHasher;
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use packed::{OnlyNull, OnlyNullMut};
+
+trait Trait {}
+
+impl Trait for u8 {}
+
+packed::dyn_pointee!(dyn Trait);
+
+fn assert_traits<P>()
+where
+    P: Debug + Clone + Copy + PartialEq + Eq + PartialOrd + Ord + Hash,
+{
+}
+
+fn assert_default<P: Default>() {}
+
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn every_pointee_kind_gets_the_traits() {
+    assert_traits::<OnlyNull<u8>>();
+    assert_traits::<OnlyNull<String>>();
+    assert_traits::<OnlyNull<[String; 4]>>();
+    assert_traits::<OnlyNull<[u8]>>();
+    assert_traits::<OnlyNull<[String]>>();
+    assert_traits::<OnlyNull<str>>();
+    assert_traits::<OnlyNull<dyn Trait>>();
+    assert_traits::<OnlyNull<dyn Debug>>();
+}
+
+#[test]
+fn default_when_metadata_has_one() {
+    assert_default::<OnlyNull<String>>();
+    assert_default::<OnlyNull<[String]>>();
+    assert_default::<OnlyNull<str>>();
+
+    assert_eq!(OnlyNull::<[u8]>::default().len(), 0);
+}
+
+#[test]
+fn comparisons_use_metadata() {
+    let short = OnlyNull::<str>::null_str(2);
+    let long = OnlyNull::<str>::null_str(5);
+
+    assert_eq!(short, short.clone());
+    assert_ne!(short, long);
+    assert!(short < long);
+    assert_eq!(hash_of(&short), hash_of(&OnlyNull::<str>::null_str(2)));
+    assert_eq!(OnlyNull::<String>::null(), OnlyNull::<String>::null());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn dyn_pointers_compare_by_vtable() {
+    let a = OnlyNull::<dyn Trait>::null_dyn::<u8>();
+    let b = a;
+
+    assert_eq!(a, b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn dyn_pointers_with_coercion_compare_by_vtable() {
+    let a = OnlyNull::<dyn Trait>::null_dyn_with(|ptr: *const u8| ptr);
+    let b = a;
+
+    assert_eq!(a, b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn dyn_pointers_from_raw_compare_by_vtable() {
+    let a = OnlyNull::try_from(std::ptr::null::<u8>() as *const dyn Trait).unwrap();
+    let b = OnlyNull::try_from(std::ptr::null::<u8>() as *const dyn Trait).unwrap();
+
+    assert_eq!(a, b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+fn assert_send_sync<P: Send + Sync>() {}
+
+#[test]
+fn send_and_sync_for_any_pointee() {
+    static SENTINEL: OnlyNull<std::cell::Cell<u8>> = OnlyNull::NULL;
+
+    assert_send_sync::<OnlyNull<std::rc::Rc<u8>>>();
+    assert_send_sync::<OnlyNull<[std::cell::Cell<u8>]>>();
+    assert_send_sync::<OnlyNull<dyn Trait>>();
+    assert_send_sync::<OnlyNullMut<std::rc::Rc<u8>>>();
+    assert_send_sync::<OnlyNullMut<dyn Trait>>();
+
+    std::thread::spawn(|| SENTINEL.is_null()).join().unwrap();
+}
+
+#[test]
+fn mutable_pointers_get_the_traits() {
+    assert_traits::<OnlyNullMut<String>>();
+    assert_traits::<OnlyNullMut<[String]>>();
+    assert_traits::<OnlyNullMut<dyn Debug>>();
+
+    let ptr: *mut [u8] = OnlyNull::<[u8]>::null_slice(3).cast_mut().into();
+    assert_eq!(OnlyNullMut::try_from(ptr).unwrap().metadata(), 3);
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "nightly")]
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display};
+
+use packed::OnlyNull;
+
+#[cfg(feature = "nightly")]
+#[test]
+fn unsize_keeps_the_pointer_null() {
+    let slice: OnlyNull<[u16]> = OnlyNull::<[u16; 5]>::null().unsize();
+    assert_eq!(slice.len(), 5);
+
+    let ptr: *const dyn Display = OnlyNull::<u32>::null().unsize::<dyn Display>().as_ptr();
+    assert!(ptr.is_null());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn into_dyn_uses_the_vtable_of_the_sized_type() {
+    // Vtables aren't guaranteed to be unique, so this goes by what's in them.
+    let null = OnlyNull::<[u8; 3]>::null().into_dyn::<dyn Debug>();
+    let raw = std::ptr::null::<[u8; 3]>() as *const dyn Debug;
+    let from_raw = OnlyNull::try_from(raw).unwrap();
+
+    assert!(null.as_ptr().is_null());
+    assert_eq!(null.size_of_val_hint(), from_raw.size_of_val_hint());
+    assert_eq!(null.align_of_val_hint(), from_raw.align_of_val_hint());
+    assert_eq!(null.size_of_val_hint(), 3);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn vtable_size_and_align_hints() {
+    let null = OnlyNull::<dyn Debug>::null_dyn::<[u16; 3]>();
+    assert_eq!(null.size_of_val_hint(), 6);
+    assert_eq!(null.align_of_val_hint(), 2);
+
+    let null = OnlyNull::<u128>::null().into_dyn::<dyn Debug>();
+    assert_eq!(null.size_of_val_hint(), std::mem::size_of::<u128>());
+    assert_eq!(null.align_of_val_hint(), std::mem::align_of::<u128>());
+}
+
+#[test]
+fn unsize_with_keeps_the_pointer_null() {
+    let slice = OnlyNull::<[u16; 5]>::null().unsize_with(|ptr| ptr as *const [u16]);
+    assert_eq!(slice.len(), 5);
+
+    let ptr = OnlyNull::<u32>::null()
+        .unsize_with(|ptr| ptr as *const dyn Display)
+        .as_ptr();
+    assert!(ptr.is_null());
+}
+
+#[test]
+fn unsize_with_only_keeps_the_metadata() {
+    let value = [1_u8, 2, 3];
+    let slice = OnlyNull::<[u8; 3]>::null().unsize_with(|_| &value[..1] as *const [u8]);
+
+    assert!(slice.as_ptr().is_null());
+    assert_eq!(slice.len(), 1);
+}
+
+#[test]
+fn null_dyn_with_uses_the_vtable_of_the_sized_type() {
+    let null = OnlyNull::<dyn Debug>::null_dyn_with(|ptr: *const [u8; 3]| ptr);
+    assert!(null.as_ptr().is_null());
+
+    #[cfg(feature = "nightly")]
+    assert_eq!(null.size_of_val_hint(), 3);
+}